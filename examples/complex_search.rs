@@ -1,15 +1,15 @@
 use std::env;
 
 use yt_api::{
-	search::{Error, ItemType, SearchList, VideoLocation},
-	ApiKey,
+	search::{ItemType, SearchList, VideoLocation},
+	ApiKey, Error,
 };
 
 /// prints the first answer of a search query
 fn main() -> Result<(), Error> {
 	futures::executor::block_on(async {
 		// take api key from enviroment variable
-		let key = ApiKey::new(&env::var("YT_API_KEY").expect("YT_API_KEY env-var not found"));
+		let key = ApiKey::new(env::var("YT_API_KEY").expect("YT_API_KEY env-var not found"));
 
 		// create the SearchList struct for the query "rust lang"
 		let result = SearchList::new(key)
@@ -22,10 +22,8 @@ fn main() -> Result<(), Error> {
 			.await?;
 
 		// outputs the video_id of the first search result
-		println!(
-			"Title: \"{}\"",
-			result.items[0].snippet.title.as_ref().unwrap()
-		);
+		let snippet = result.items[0].snippet.as_ref().unwrap();
+		println!("Title: \"{}\"", snippet.title.as_ref().unwrap());
 		println!(
 			"https://youtube.com/watch?v={}",
 			result.items[0].id.video_id.as_ref().unwrap()
@@ -1,15 +1,15 @@
 use std::env;
 
 use yt_api::{
-	search::{Error, ItemType, SearchList},
-	ApiKey,
+	search::{ItemType, SearchList},
+	ApiKey, Error,
 };
 
 /// prints the first answer of a search query
 fn main() -> Result<(), Error> {
 	futures::executor::block_on(async {
 		// take api key from enviroment variable
-		let key = ApiKey::new(&env::var("YT_API_KEY").expect("YT_API_KEY env-var not found"));
+		let key = ApiKey::new(env::var("YT_API_KEY").expect("YT_API_KEY env-var not found"));
 
 		// create the SearchList struct for the query "rust lang"
 		let result = SearchList::new(key)
@@ -18,10 +18,8 @@ fn main() -> Result<(), Error> {
 			.await?;
 
 		// outputs the title of the first search result
-		println!(
-			"Title: \"{}\"",
-			result.items[0].snippet.title.as_ref().unwrap()
-		);
+		let snippet = result.items[0].snippet.as_ref().unwrap();
+		println!("Title: \"{}\"", snippet.title.as_ref().unwrap());
 		// outputs the video id of the first search result
 		println!(
 			"https://youtube.com/watch?v={}",
@@ -0,0 +1,623 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::{PageInfo, Thumbnails},
+	error::Validation,
+	fields::{Schema, ANY, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the activities endpoint
+///
+/// exactly one of [`channel_id`](#method.channel_id) and [`mine`](#method.mine) has to be set.
+pub struct ActivitiesList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<ActivitiesListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ActivitiesListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<ActivityPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	channel_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	mine: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	published_after: Option<DateTime<Utc>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	published_before: Option<DateTime<Utc>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	region_code: Option<String>,
+}
+
+impl ActivitiesList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(ActivitiesListData {
+				fields: None,
+				part: vec![ActivityPart::Snippet],
+				channel_id: None,
+				mine: None,
+				max_results: None,
+				page_token: None,
+				published_after: None,
+				published_before: None,
+				region_code: client.region_code().cloned(),
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = ActivityPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the activities of a channel
+	#[must_use]
+	pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.channel_id = Some(channel_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// get the activities of the authorized user
+	#[must_use]
+	pub fn mine(mut self, mine: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.mine = Some(mine);
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn published_after(mut self, published_after: impl Into<DateTime<Utc>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.published_after = Some(published_after.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn published_before(mut self, published_before: impl Into<DateTime<Utc>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.published_before = Some(published_before.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn region_code(mut self, region_code: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.region_code = Some(region_code.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<Activity, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for ActivitiesList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for ActivitiesListData {
+	const ENDPOINT: Endpoint = Endpoint::ActivitiesList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		ensure!(
+			self.channel_id.is_some() != self.mine.is_some(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of channelId and mine must be set",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &ACTIVITY_SCHEMA),
+]);
+
+static ACTIVITY_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelId", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			("thumbnails", &THUMBNAILS),
+			("channelTitle", &LEAF),
+			("type", &LEAF),
+			("groupId", &LEAF),
+		]),
+	),
+	(
+		"contentDetails",
+		&Schema(&[
+			("upload", &ANY),
+			("like", &ANY),
+			("favorite", &ANY),
+			("comment", &ANY),
+			("subscription", &ANY),
+			("playlistItem", &ANY),
+			("recommendation", &ANY),
+			("bulletin", &ANY),
+			("social", &ANY),
+			("channelItem", &ANY),
+		]),
+	),
+]);
+
+/// resource part which can be requested with [`part`](./struct.ActivitiesList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityPart {
+	ContentDetails,
+	Id,
+	Snippet,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub prev_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<Activity>,
+}
+
+/// activity resource, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Activity {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	/// `None` if the part was not requested or a field mask removed all of its fields
+	#[serde(deserialize_with = "ContentDetails::deserialize_optional")]
+	pub content_details: Option<ContentDetails>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub published_at: Option<DateTime<Utc>>,
+	pub channel_id: Option<String>,
+	pub title: Option<String>,
+	pub description: Option<String>,
+	pub thumbnails: Option<Thumbnails>,
+	pub channel_title: Option<String>,
+	/// type of the activity like `upload`, matches the variant of the content details
+	#[serde(rename = "type")]
+	pub activity_type: Option<String>,
+	/// id shared by related activities
+	pub group_id: Option<String>,
+}
+
+/// details of the resource an activity is about, depending on the type of the activity
+#[derive(Debug, Clone)]
+pub enum ContentDetails {
+	Upload(Upload),
+	Like(Resource),
+	Favorite(Resource),
+	Comment(Resource),
+	Subscription(Resource),
+	PlaylistItem(PlaylistItem),
+	Recommendation(Recommendation),
+	Bulletin(Resource),
+	Social(Social),
+	ChannelItem(Resource),
+	/// activity type this crate does not know about
+	Other {
+		activity_type: String,
+		details: serde_json::Value,
+	},
+}
+
+impl ContentDetails {
+	/// details of the single activity in `map`, `None` if it is empty
+	fn from_map(
+		map: serde_json::Map<String, serde_json::Value>,
+	) -> Result<Option<Self>, serde_json::Error> {
+		let (activity_type, details) = match map.into_iter().next() {
+			Some(activity) => activity,
+			None => return Ok(None),
+		};
+		let content_details = match activity_type.as_str() {
+			"upload" => serde_json::from_value(details).map(Self::Upload),
+			"like" => serde_json::from_value(details).map(Self::Like),
+			"favorite" => serde_json::from_value(details).map(Self::Favorite),
+			"comment" => serde_json::from_value(details).map(Self::Comment),
+			"subscription" => serde_json::from_value(details).map(Self::Subscription),
+			"playlistItem" => serde_json::from_value(details).map(Self::PlaylistItem),
+			"recommendation" => serde_json::from_value(details).map(Self::Recommendation),
+			"bulletin" => serde_json::from_value(details).map(Self::Bulletin),
+			"social" => serde_json::from_value(details).map(Self::Social),
+			"channelItem" => serde_json::from_value(details).map(Self::ChannelItem),
+			_ => Ok(Self::Other {
+				activity_type,
+				details,
+			}),
+		};
+		content_details.map(Some)
+	}
+
+	/// deserialize the part, leaving it empty when a field mask removed the activity
+	fn deserialize_optional<'de, D>(deserializer: D) -> Result<Option<Self>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		match Option::<serde_json::Map<String, serde_json::Value>>::deserialize(deserializer)? {
+			Some(map) => Self::from_map(map).map_err(D::Error::custom),
+			None => Ok(None),
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for ContentDetails {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let map = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+		Self::from_map(map)
+			.map_err(D::Error::custom)?
+			.ok_or_else(|| D::Error::custom("contentDetails without an activity"))
+	}
+}
+
+/// uploaded video
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Upload {
+	pub video_id: String,
+}
+
+/// resource which was liked, favorited, commented on, subscribed to or posted
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Resource {
+	pub resource_id: ResourceId,
+}
+
+/// video added to a playlist
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PlaylistItem {
+	pub resource_id: ResourceId,
+	pub playlist_id: String,
+	pub playlist_item_id: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Recommendation {
+	pub resource_id: ResourceId,
+	/// `videoFavorited`, `videoLiked` or `videoWatched`
+	pub reason: Option<String>,
+	/// resource which caused the recommendation
+	pub seed_resource_id: Option<ResourceId>,
+}
+
+/// post on a social network
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Social {
+	/// `facebook`, `googlePlus`, `twitter` or `unspecified`
+	#[serde(rename = "type")]
+	pub network: Option<String>,
+	pub resource_id: Option<ResourceId>,
+	pub author: Option<String>,
+	pub reference_url: Option<String>,
+	pub image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResourceId {
+	pub kind: String,
+	pub video_id: Option<String>,
+	pub channel_id: Option<String>,
+	pub playlist_id: Option<String>,
+}
+
+impl Page for Response {
+	type Item = Activity;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<Activity> {
+		self.items
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Activity, ContentDetails, Resource, Response};
+
+	fn content_details(json: &str) -> Option<ContentDetails> {
+		let activity = format!(r#"{{"id":"activity","contentDetails":{}}}"#, json);
+		serde_json::from_str::<Activity>(&activity)
+			.unwrap()
+			.content_details
+	}
+
+	fn video_id(resource: &Resource) -> Option<&str> {
+		resource.resource_id.video_id.as_deref()
+	}
+
+	#[test]
+	fn deserializes_every_activity_type() {
+		let resource = r#"{"resourceId":{"kind":"youtube#video","videoId":"video"}}"#;
+		let details = |activity_type: &str| {
+			content_details(&format!(r#"{{"{}":{}}}"#, activity_type, resource))
+		};
+
+		match content_details(r#"{"upload":{"videoId":"video"}}"#) {
+			Some(ContentDetails::Upload(upload)) => assert_eq!(upload.video_id, "video"),
+			other => panic!("{:?}", other),
+		}
+		match details("like") {
+			Some(ContentDetails::Like(like)) => assert_eq!(video_id(&like), Some("video")),
+			other => panic!("{:?}", other),
+		}
+		match details("favorite") {
+			Some(ContentDetails::Favorite(favorite)) => {
+				assert_eq!(video_id(&favorite), Some("video"))
+			}
+			other => panic!("{:?}", other),
+		}
+		match details("comment") {
+			Some(ContentDetails::Comment(comment)) => {
+				assert_eq!(video_id(&comment), Some("video"))
+			}
+			other => panic!("{:?}", other),
+		}
+		match details("subscription") {
+			Some(ContentDetails::Subscription(subscription)) => {
+				assert_eq!(video_id(&subscription), Some("video"))
+			}
+			other => panic!("{:?}", other),
+		}
+		match details("bulletin") {
+			Some(ContentDetails::Bulletin(bulletin)) => {
+				assert_eq!(video_id(&bulletin), Some("video"))
+			}
+			other => panic!("{:?}", other),
+		}
+		match details("channelItem") {
+			Some(ContentDetails::ChannelItem(item)) => assert_eq!(video_id(&item), Some("video")),
+			other => panic!("{:?}", other),
+		}
+		match content_details(
+			r#"{"playlistItem":{"resourceId":{"kind":"youtube#video","videoId":"video"},"playlistId":"playlist","playlistItemId":"item"}}"#,
+		) {
+			Some(ContentDetails::PlaylistItem(item)) => {
+				assert_eq!(item.resource_id.video_id.as_deref(), Some("video"));
+				assert_eq!(item.playlist_id, "playlist");
+				assert_eq!(item.playlist_item_id, "item");
+			}
+			other => panic!("{:?}", other),
+		}
+		match content_details(
+			r#"{"recommendation":{"resourceId":{"kind":"youtube#video","videoId":"video"},"reason":"videoLiked","seedResourceId":{"kind":"youtube#video","videoId":"seed"}}}"#,
+		) {
+			Some(ContentDetails::Recommendation(recommendation)) => {
+				assert_eq!(recommendation.reason.as_deref(), Some("videoLiked"));
+				assert_eq!(
+					recommendation
+						.seed_resource_id
+						.and_then(|seed| seed.video_id)
+						.as_deref(),
+					Some("seed")
+				);
+			}
+			other => panic!("{:?}", other),
+		}
+		match content_details(
+			r#"{"social":{"type":"twitter","author":"someone","referenceUrl":"https://example.com"}}"#,
+		) {
+			Some(ContentDetails::Social(social)) => {
+				assert_eq!(social.network.as_deref(), Some("twitter"));
+				assert_eq!(social.author.as_deref(), Some("someone"));
+				assert_eq!(social.reference_url.as_deref(), Some("https://example.com"));
+			}
+			other => panic!("{:?}", other),
+		}
+	}
+
+	#[test]
+	fn keeps_unknown_activity_types() {
+		match content_details(r#"{"promotedItem":{"ctaType":"visitAdvertiserSite"}}"#) {
+			Some(ContentDetails::Other {
+				activity_type,
+				details,
+			}) => {
+				assert_eq!(activity_type, "promotedItem");
+				assert_eq!(details["ctaType"], "visitAdvertiserSite");
+			}
+			other => panic!("{:?}", other),
+		}
+	}
+
+	#[test]
+	fn treats_missing_content_details_as_none() {
+		assert!(content_details("{}").is_none());
+		assert!(content_details("null").is_none());
+		let activity: Activity = serde_json::from_str(r#"{"id":"activity"}"#).unwrap();
+		assert!(activity.content_details.is_none());
+	}
+
+	#[test]
+	fn does_not_fail_a_page_with_empty_content_details() {
+		let page: Response = serde_json::from_str(
+			r#"{"items":[{"id":"masked","contentDetails":{}},{"id":"upload","contentDetails":{"upload":{"videoId":"video"}}}]}"#,
+		)
+		.unwrap();
+
+		assert!(page.items[0].content_details.is_none());
+		assert!(matches!(
+			page.items[1].content_details,
+			Some(ContentDetails::Upload(_))
+		));
+	}
+
+	#[test]
+	fn rejects_malformed_content_details() {
+		assert!(
+			serde_json::from_str::<Activity>(r#"{"contentDetails":{"upload":{"videoId":1}}}"#)
+				.is_err()
+		);
+		assert!(serde_json::from_str::<Activity>(r#"{"contentDetails":[]}"#).is_err());
+		assert!(serde_json::from_str::<ContentDetails>("{}").is_err());
+	}
+}
@@ -0,0 +1,281 @@
+use std::{fmt, sync::Arc};
+
+use chrono::Duration;
+use futures::lock::Mutex;
+use log::debug;
+use snafu::ResultExt;
+
+use super::{AuthError, OAuthClient, Store, Token, TokenStore};
+
+/// source of access tokens which refreshes them before they expire
+///
+/// an `Authenticator` is used as [`Credentials`](./enum.Credentials.html) of a
+/// [`Client`](../struct.Client.html). Every request asks it for a valid access token, and a
+/// request rejected with status 401 is sent once more after refreshing the token. Refreshed
+/// tokens are written to the [`TokenStore`](./trait.TokenStore.html).
+///
+/// cloning an `Authenticator` is cheap, all clones share the same token.
+///
+/// ```rust
+/// # use yt_api::{
+/// #     auth::{Authenticator, MemoryTokenStore, OAuthClient, Token},
+/// #     transport::{HttpResponse, MemoryTransport},
+/// #     Client,
+/// # };
+/// # let authorization_server = MemoryTransport::new();
+/// # authorization_server.push_response(HttpResponse::new(200, r#"{"access_token":"fresh","expires_in":3599}"#));
+/// # let api = MemoryTransport::new();
+/// # api.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+/// # futures::executor::block_on(async {
+/// let authenticator = Authenticator::new(
+///     OAuthClient::new("client-id")
+///         .client_secret("client-secret")
+///         # .transport(authorization_server.clone())
+///         ,
+///     MemoryTokenStore::new(),
+/// );
+/// authenticator
+///     .set_token(Token {
+///         access_token: String::from("expired"),
+///         refresh_token: Some(String::from("refresh")),
+///         expires_at: Some(chrono::Utc::now()),
+///         scopes: Vec::new(),
+///     })
+///     .await?;
+///
+/// let client = Client::builder(authenticator.clone())
+///     # .transport(api.clone())
+///     .build();
+/// client.channels().mine(true).await.unwrap();
+/// # assert_eq!(api.requests()[0].headers, vec![("authorization", String::from("Bearer fresh"))]);
+/// assert_eq!(authenticator.token().await?.access_token, "fresh");
+/// # Ok::<(), yt_api::auth::AuthError>(())
+/// # }).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Authenticator {
+	client: OAuthClient,
+	store: Arc<dyn TokenStore>,
+	token: Arc<Mutex<Option<Token>>>,
+	margin: Duration,
+}
+
+impl Authenticator {
+	#[must_use]
+	pub fn new(client: OAuthClient, store: impl TokenStore + 'static) -> Self {
+		Self {
+			client,
+			store: Arc::new(store),
+			token: Arc::default(),
+			margin: Duration::minutes(5),
+		}
+	}
+
+	/// refresh tokens which expire within `margin`, defaults to five minutes
+	#[must_use]
+	pub fn refresh_margin(mut self, margin: Duration) -> Self {
+		self.margin = margin;
+		self
+	}
+
+	/// replace the token, usually with one granted by a flow
+	pub async fn set_token(&self, token: Token) -> Result<(), AuthError> {
+		let mut current = self.token.lock().await;
+		self.store.store(&token).context(Store)?;
+		*current = Some(token);
+		Ok(())
+	}
+
+	/// valid token, refreshed if it expires within the refresh margin
+	///
+	/// the token is loaded from the store on first use.
+	pub async fn token(&self) -> Result<Token, AuthError> {
+		let mut current = self.token.lock().await;
+		let token = self.current(&mut current)?;
+		if !token.expires_within(self.margin) {
+			return Ok(token);
+		}
+		if token.refresh_token.is_none() && !token.is_expired() {
+			return Ok(token);
+		}
+		self.refresh_locked(&mut current, token).await
+	}
+
+	/// refresh the token even if it did not expire yet
+	pub async fn refresh(&self) -> Result<Token, AuthError> {
+		let mut current = self.token.lock().await;
+		let token = self.current(&mut current)?;
+		self.refresh_locked(&mut current, token).await
+	}
+
+	/// refresh the token after `access_token` was rejected, unless another request already did
+	///
+	/// returns whether there is a new token to send the request with.
+	pub(crate) async fn reject(&self, access_token: &str) -> Result<bool, AuthError> {
+		let mut current = self.token.lock().await;
+		let token = self.current(&mut current)?;
+		if token.access_token != access_token {
+			return Ok(true);
+		}
+		if token.refresh_token.is_none() {
+			return Ok(false);
+		}
+		self.refresh_locked(&mut current, token).await.map(|_| true)
+	}
+
+	/// revoke the grant at the authorization server and remove the token from the store
+	///
+	/// revoking the refresh token also invalidates the access tokens issued with it.
+	pub async fn revoke(&self) -> Result<(), AuthError> {
+		let mut current = self.token.lock().await;
+		let token = self.current(&mut current)?;
+		self.client
+			.revoke(token.refresh_token.as_ref().unwrap_or(&token.access_token))
+			.await?;
+		self.store.clear().context(Store)?;
+		*current = None;
+		Ok(())
+	}
+
+	/// cached token, loaded from the store if there is none yet
+	fn current(&self, current: &mut Option<Token>) -> Result<Token, AuthError> {
+		if current.is_none() {
+			*current = self.store.load().context(Store)?;
+		}
+		current.clone().ok_or(AuthError::NoToken)
+	}
+
+	async fn refresh_locked(
+		&self,
+		current: &mut Option<Token>,
+		token: Token,
+	) -> Result<Token, AuthError> {
+		let refresh_token = token.refresh_token.ok_or(AuthError::NoRefreshToken)?;
+		debug!("refreshing access token");
+		let mut refreshed = self.client.refresh_token(&refresh_token).await?;
+		if refreshed.scopes.is_empty() {
+			refreshed.scopes = token.scopes;
+		}
+		self.store.store(&refreshed).context(Store)?;
+		*current = Some(refreshed.clone());
+		Ok(refreshed)
+	}
+}
+
+impl fmt::Debug for Authenticator {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Authenticator")
+			.field("client", &self.client)
+			.field("store", &self.store)
+			.field("margin", &self.margin)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Duration, Utc};
+	use futures::executor::block_on;
+
+	use super::Authenticator;
+	use crate::{
+		auth::{MemoryTokenStore, OAuthClient, Token},
+		transport::{HttpResponse, MemoryTransport},
+		Client,
+	};
+
+	const UNAUTHORIZED: &str = r#"{"error":{"code":401,"message":"Request had invalid authentication credentials.","errors":[{"message":"Invalid Credentials","domain":"global","reason":"authError","location":"Authorization","locationType":"header"}],"status":"UNAUTHENTICATED"}}"#;
+
+	fn authenticator(
+		authorization_server: &MemoryTransport,
+		expires_in: Duration,
+	) -> Authenticator {
+		let authenticator = Authenticator::new(
+			OAuthClient::new("client-id").transport(authorization_server.clone()),
+			MemoryTokenStore::new(),
+		);
+		block_on(authenticator.set_token(Token {
+			access_token: String::from("stale"),
+			refresh_token: Some(String::from("refresh")),
+			expires_at: Some(Utc::now() + expires_in),
+			scopes: Vec::new(),
+		}))
+		.unwrap();
+		authenticator
+	}
+
+	fn fresh_token() -> HttpResponse {
+		HttpResponse::new(200, r#"{"access_token":"fresh","expires_in":3599}"#)
+	}
+
+	fn bearer(transport: &MemoryTransport) -> Vec<String> {
+		transport
+			.requests()
+			.iter()
+			.map(|request| request.headers[0].1.clone())
+			.collect()
+	}
+
+	#[test]
+	fn refreshes_tokens_expiring_within_the_margin() {
+		let authorization_server = MemoryTransport::new();
+		authorization_server.push_response(fresh_token());
+		let authenticator = authenticator(&authorization_server, Duration::minutes(4));
+
+		let token = block_on(authenticator.token()).unwrap();
+
+		assert_eq!(token.access_token, "fresh");
+		assert_eq!(token.refresh_token.as_deref(), Some("refresh"));
+		let requests = authorization_server.requests();
+		assert_eq!(requests.len(), 1);
+		let form = String::from_utf8(requests[0].body.clone().unwrap()).unwrap();
+		assert!(form.starts_with("grant_type=refresh_token&refresh_token=refresh&"));
+	}
+
+	#[test]
+	fn keeps_tokens_expiring_after_the_margin() {
+		let authorization_server = MemoryTransport::new();
+		let authenticator = authenticator(&authorization_server, Duration::minutes(4))
+			.refresh_margin(Duration::minutes(3));
+
+		let token = block_on(authenticator.token()).unwrap();
+
+		assert_eq!(token.access_token, "stale");
+		assert!(authorization_server.requests().is_empty());
+	}
+
+	#[test]
+	fn retries_once_with_a_refreshed_token_after_401() {
+		let authorization_server = MemoryTransport::new();
+		authorization_server.push_response(fresh_token());
+		let api = MemoryTransport::new();
+		api.push_response(HttpResponse::new(401, UNAUTHORIZED));
+		api.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+		let client = Client::builder(authenticator(&authorization_server, Duration::hours(1)))
+			.transport(api.clone())
+			.build();
+
+		block_on(client.channels().mine(true)).unwrap();
+
+		assert_eq!(bearer(&api), ["Bearer stale", "Bearer fresh"]);
+		assert_eq!(authorization_server.requests().len(), 1);
+	}
+
+	#[test]
+	fn gives_up_when_the_refreshed_token_is_rejected_too() {
+		let authorization_server = MemoryTransport::new();
+		authorization_server.push_response(fresh_token());
+		let api = MemoryTransport::new();
+		api.push_response(HttpResponse::new(401, UNAUTHORIZED));
+		api.push_response(HttpResponse::new(401, UNAUTHORIZED));
+		let client = Client::builder(authenticator(&authorization_server, Duration::hours(1)))
+			.transport(api.clone())
+			.build();
+
+		let error = block_on(client.channels().mine(true)).unwrap_err();
+
+		assert!(error.is_auth_error(), "{}", error);
+		assert_eq!(bearer(&api), ["Bearer stale", "Bearer fresh"]);
+		assert_eq!(authorization_server.requests().len(), 1);
+	}
+}
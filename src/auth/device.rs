@@ -0,0 +1,182 @@
+use std::time::{Duration, Instant};
+
+use futures_timer::Delay;
+use log::debug;
+use serde::Deserialize;
+
+use super::{AuthError, OAuthClient, Token};
+
+/// oauth 2.0 device authorization grant for devices without a browser
+///
+/// [`start`](#method.start) requests a user code, which the user enters at the verification
+/// url on another device.
+/// [`finish`](./struct.DeviceAuthorization.html#method.finish) polls the token endpoint until
+/// the user granted or denied access.
+///
+/// ```rust
+/// # use yt_api::{
+/// #     auth::{scope, DeviceFlow, OAuthClient},
+/// #     transport::{HttpResponse, MemoryTransport},
+/// # };
+/// # let authorization_server = MemoryTransport::new();
+/// # authorization_server.push_response(HttpResponse::new(
+/// #     200,
+/// #     r#"{"device_code":"device","user_code":"ABCD-EFGH","verification_url":"https://www.google.com/device","expires_in":1800,"interval":0}"#,
+/// # ));
+/// # authorization_server.push_response(HttpResponse::new(428, r#"{"error":"authorization_pending"}"#));
+/// # authorization_server.push_response(HttpResponse::new(200, r#"{"access_token":"access","expires_in":3599}"#));
+/// # futures::executor::block_on(async {
+/// let client = OAuthClient::new("client-id")
+///     .client_secret("client-secret")
+///     # .transport(authorization_server.clone())
+///     ;
+/// let authorization = DeviceFlow::new(client)
+///     .scope(scope::YOUTUBE_READONLY)
+///     .start()
+///     .await?;
+/// println!(
+///     "enter {} at {}",
+///     authorization.user_code(),
+///     authorization.verification_url()
+/// );
+///
+/// let token = authorization.finish().await?;
+/// assert_eq!(token.access_token, "access");
+/// # assert_eq!(authorization_server.requests().len(), 3);
+/// # Ok::<(), yt_api::auth::AuthError>(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeviceFlow {
+	client: OAuthClient,
+	device_url: String,
+	scopes: Vec<String>,
+}
+
+/// authorization which waits for the user to enter the user code
+#[derive(Debug)]
+pub struct DeviceAuthorization {
+	client: OAuthClient,
+	device_code: String,
+	user_code: String,
+	verification_url: String,
+	interval: Duration,
+	expires_at: Instant,
+}
+
+/// answer of the device authorization endpoint
+#[derive(Debug, Deserialize)]
+struct DeviceCodeResponse {
+	device_code: String,
+	user_code: String,
+	/// google deviates from rfc 8628, which calls this `verification_uri`
+	#[serde(alias = "verification_uri")]
+	verification_url: String,
+	expires_in: u64,
+	#[serde(default = "default_interval")]
+	interval: u64,
+}
+
+fn default_interval() -> u64 {
+	5
+}
+
+impl DeviceFlow {
+	const DEVICE_URL: &'static str = "https://oauth2.googleapis.com/device/code";
+	const GRANT_TYPE: &'static str = "urn:ietf:params:oauth:grant-type:device_code";
+	/// increase of the polling interval requested by `slow_down`
+	const SLOW_DOWN: Duration = Duration::from_secs(5);
+
+	#[must_use]
+	pub fn new(client: OAuthClient) -> Self {
+		Self {
+			client,
+			device_url: String::from(Self::DEVICE_URL),
+			scopes: Vec::new(),
+		}
+	}
+
+	/// request access to `scope`, see [`scope`](./scope/index.html) for the youtube scopes
+	#[must_use]
+	pub fn scope(mut self, scope: impl Into<String>) -> Self {
+		self.scopes.push(scope.into());
+		self
+	}
+
+	/// url of the device authorization endpoint, defaults to the one of google
+	#[must_use]
+	pub fn device_url(mut self, device_url: impl Into<String>) -> Self {
+		self.device_url = device_url.into();
+		self
+	}
+
+	/// request a user code from the authorization server
+	pub async fn start(self) -> Result<DeviceAuthorization, AuthError> {
+		let scope = self.scopes.join(" ");
+		let response: DeviceCodeResponse = self
+			.client
+			.post_form(&self.device_url, &[("scope", &scope)])
+			.await?;
+		Ok(DeviceAuthorization {
+			client: self.client,
+			device_code: response.device_code,
+			user_code: response.user_code,
+			verification_url: response.verification_url,
+			interval: Duration::from_secs(response.interval),
+			expires_at: Instant::now() + Duration::from_secs(response.expires_in),
+		})
+	}
+}
+
+impl DeviceAuthorization {
+	/// code the user has to enter at the verification url
+	#[must_use]
+	pub fn user_code(&self) -> &str {
+		&self.user_code
+	}
+
+	/// url where the user grants access
+	#[must_use]
+	pub fn verification_url(&self) -> &str {
+		&self.verification_url
+	}
+
+	/// time after which the user code is no longer accepted
+	#[must_use]
+	pub fn expires_at(&self) -> Instant {
+		self.expires_at
+	}
+
+	/// poll the token endpoint until the user granted access
+	///
+	/// polls every `interval` seconds as told by the authorization server and backs off when
+	/// asked to `slow_down`.
+	pub async fn finish(self) -> Result<Token, AuthError> {
+		let mut interval = self.interval;
+		loop {
+			Delay::new(interval).await;
+			if Instant::now() >= self.expires_at {
+				return Err(AuthError::Expired);
+			}
+			let error = match self
+				.client
+				.request_token(&[
+					("grant_type", DeviceFlow::GRANT_TYPE),
+					("device_code", &self.device_code),
+				])
+				.await
+			{
+				Ok(token) => return Ok(token),
+				Err(error) => error,
+			};
+			match error.error_code() {
+				Some("authorization_pending") => {}
+				Some("slow_down") => {
+					interval += DeviceFlow::SLOW_DOWN;
+					debug!("slowing down device polling to {:?}", interval);
+				}
+				_ => return Err(error),
+			}
+		}
+	}
+}
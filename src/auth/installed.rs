@@ -0,0 +1,246 @@
+use std::{
+	collections::HashMap,
+	io::{self, Read, Write},
+	net::{Ipv4Addr, TcpListener, TcpStream},
+	thread,
+	time::Duration,
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use futures::channel::oneshot;
+use rand::{distributions::Alphanumeric, Rng};
+use sha2::{Digest, Sha256};
+use snafu::ResultExt;
+
+use super::{AuthError, Encoding, Listener, OAuthClient, Token};
+
+/// oauth 2.0 flow for installed applications, which receives the authorization code on a
+/// loopback redirect and protects it with pkce
+///
+/// [`start`](#method.start) listens on a free port of `127.0.0.1` and returns the url of the
+/// consent screen, which has to be opened in a browser.
+/// [`finish`](./struct.PendingAuthorization.html#method.finish) waits for the redirect and
+/// exchanges the code for a token.
+///
+/// ```rust
+/// # use std::{io::Write, net::TcpStream};
+/// # use yt_api::{
+/// #     auth::{scope, InstalledFlow, OAuthClient},
+/// #     transport::{HttpResponse, MemoryTransport},
+/// # };
+/// # let token_endpoint = MemoryTransport::new();
+/// # token_endpoint.push_response(HttpResponse::new(
+/// #     200,
+/// #     r#"{"access_token":"access","refresh_token":"refresh","expires_in":3599}"#,
+/// # ));
+/// # futures::executor::block_on(async {
+/// let client = OAuthClient::new("client-id")
+///     .client_secret("client-secret")
+///     # .transport(token_endpoint.clone())
+///     ;
+/// let pending = InstalledFlow::new(client)
+///     .scope(scope::YOUTUBE_READONLY)
+///     .start()?;
+/// println!("open {} to authorize the application", pending.authorization_url());
+/// # let state = pending.authorization_url().split("state=").nth(1).unwrap().split('&').next().unwrap().to_string();
+/// # let address = pending.redirect_uri().trim_start_matches("http://").to_string();
+/// # std::thread::spawn(move || {
+/// #     let mut browser = TcpStream::connect(address).unwrap();
+/// #     write!(browser, "GET /?code=code&state={} HTTP/1.1\r\n\r\n", state).unwrap();
+/// # });
+///
+/// let token = pending.finish().await?;
+/// assert_eq!(token.access_token, "access");
+/// # let exchange = String::from_utf8(token_endpoint.requests()[0].body.clone().unwrap()).unwrap();
+/// # assert!(exchange.contains("grant_type=authorization_code&code=code&"));
+/// # assert!(exchange.contains("code_verifier="));
+/// # Ok::<(), yt_api::auth::AuthError>(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct InstalledFlow {
+	client: OAuthClient,
+	auth_url: String,
+	scopes: Vec<String>,
+}
+
+/// authorization which waits for the user to grant access in the browser
+#[derive(Debug)]
+pub struct PendingAuthorization {
+	client: OAuthClient,
+	authorization_url: String,
+	redirect_uri: String,
+	verifier: String,
+	state: String,
+	redirect: oneshot::Receiver<Result<HashMap<String, String>, AuthError>>,
+}
+
+impl InstalledFlow {
+	const AUTH_URL: &'static str = "https://accounts.google.com/o/oauth2/v2/auth";
+
+	#[must_use]
+	pub fn new(client: OAuthClient) -> Self {
+		Self {
+			client,
+			auth_url: String::from(Self::AUTH_URL),
+			scopes: Vec::new(),
+		}
+	}
+
+	/// request access to `scope`, see [`scope`](./scope/index.html) for the youtube scopes
+	#[must_use]
+	pub fn scope(mut self, scope: impl Into<String>) -> Self {
+		self.scopes.push(scope.into());
+		self
+	}
+
+	/// url of the consent screen, defaults to the one of google
+	#[must_use]
+	pub fn auth_url(mut self, auth_url: impl Into<String>) -> Self {
+		self.auth_url = auth_url.into();
+		self
+	}
+
+	/// listen for the redirect and build the url of the consent screen
+	pub fn start(self) -> Result<PendingAuthorization, AuthError> {
+		let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).context(Listener)?;
+		listener.set_nonblocking(true).context(Listener)?;
+		let redirect_uri = format!("http://{}", listener.local_addr().context(Listener)?);
+
+		let verifier = random_string(64);
+		let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+		let state = random_string(32);
+		let scope = self.scopes.join(" ");
+		let query = serde_urlencoded::to_string([
+			("client_id", self.client.client_id()),
+			("redirect_uri", &redirect_uri),
+			("response_type", "code"),
+			("scope", &scope),
+			("code_challenge", &challenge),
+			("code_challenge_method", "S256"),
+			("state", &state),
+			("access_type", "offline"),
+		])
+		.context(Encoding)?;
+		let separator = if self.auth_url.contains('?') {
+			'&'
+		} else {
+			'?'
+		};
+		let authorization_url = format!("{}{}{}", self.auth_url, separator, query);
+
+		let (sender, redirect) = oneshot::channel();
+		thread::spawn(move || listen(&listener, sender));
+
+		Ok(PendingAuthorization {
+			client: self.client,
+			authorization_url,
+			redirect_uri,
+			verifier,
+			state,
+			redirect,
+		})
+	}
+}
+
+impl PendingAuthorization {
+	/// url of the consent screen to open in a browser
+	#[must_use]
+	pub fn authorization_url(&self) -> &str {
+		&self.authorization_url
+	}
+
+	/// loopback address the browser is redirected to
+	#[must_use]
+	pub fn redirect_uri(&self) -> &str {
+		&self.redirect_uri
+	}
+
+	/// wait for the redirect and exchange the authorization code for a token
+	pub async fn finish(self) -> Result<Token, AuthError> {
+		let parameters = self.redirect.await.map_err(|_| AuthError::Cancelled)??;
+		if parameters.get("state") != Some(&self.state) {
+			return Err(AuthError::StateMismatch);
+		}
+		if let Some(error) = parameters.get("error") {
+			return Err(AuthError::Rejected {
+				error: error.clone(),
+				description: parameters.get("error_description").cloned(),
+			});
+		}
+		let code = parameters.get("code").map(String::as_str).unwrap_or("");
+		self.client
+			.request_token(&[
+				("grant_type", "authorization_code"),
+				("code", code),
+				("redirect_uri", &self.redirect_uri),
+				("code_verifier", &self.verifier),
+			])
+			.await
+	}
+}
+
+fn random_string(length: usize) -> String {
+	rand::thread_rng()
+		.sample_iter(&Alphanumeric)
+		.take(length)
+		.map(char::from)
+		.collect()
+}
+
+/// accept connections until one carries the answer of the authorization server
+fn listen(
+	listener: &TcpListener,
+	sender: oneshot::Sender<Result<HashMap<String, String>, AuthError>>,
+) {
+	while !sender.is_canceled() {
+		match listener.accept() {
+			Ok((stream, _)) => {
+				if let Some(parameters) = answer(stream) {
+					let _ = sender.send(Ok(parameters));
+					return;
+				}
+			}
+			Err(error) if error.kind() == io::ErrorKind::WouldBlock => {
+				thread::sleep(Duration::from_millis(50));
+			}
+			Err(source) => {
+				let _ = sender.send(Err(AuthError::Listener { source }));
+				return;
+			}
+		}
+	}
+}
+
+/// read the query of a redirect and tell the user to go back to the application
+fn answer(mut stream: TcpStream) -> Option<HashMap<String, String>> {
+	stream.set_nonblocking(false).ok()?;
+	stream.set_read_timeout(Some(Duration::from_secs(5))).ok()?;
+	let mut request = Vec::new();
+	let mut buffer = [0; 1024];
+	while !request.windows(4).any(|window| window == b"\r\n\r\n") && request.len() < 8192 {
+		match stream.read(&mut buffer) {
+			Ok(0) | Err(_) => break,
+			Ok(read) => request.extend_from_slice(&buffer[..read]),
+		}
+	}
+	let request = String::from_utf8_lossy(&request);
+	let target = request.lines().next()?.split(' ').nth(1)?;
+	let parameters: HashMap<String, String> = target
+		.split_once('?')
+		.and_then(|(_, query)| serde_urlencoded::from_str(query).ok())
+		.unwrap_or_default();
+	if !parameters.contains_key("code") && !parameters.contains_key("error") {
+		let _ = stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n");
+		return None;
+	}
+	let body =
+		"<html><body>The authorization is complete, you can close this window.</body></html>";
+	let _ = write!(
+		stream,
+		"HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+		body.len(),
+		body
+	);
+	Some(parameters)
+}
@@ -0,0 +1,417 @@
+//! credentials and oauth 2.0 flows to authorize requests
+//!
+//! requests for public data can be authorized with an [`ApiKey`](../struct.ApiKey.html),
+//! requests for the data of a user, like [`SearchList::for_mine`] or
+//! [`ChannelsList::mine`], need an oauth 2.0 access token. Tokens are obtained with the
+//! [`InstalledFlow`](./struct.InstalledFlow.html), which opens the consent screen in a browser
+//! and receives the result on a loopback redirect, or with the
+//! [`DeviceFlow`](./struct.DeviceFlow.html) on devices without a browser. An
+//! [`Authenticator`](./struct.Authenticator.html) keeps the token valid for long running
+//! applications and persists it in a [`TokenStore`](./trait.TokenStore.html).
+//!
+//! [`SearchList::for_mine`]: ../search/struct.SearchList.html#method.for_mine
+//! [`ChannelsList::mine`]: ../channels/struct.ChannelsList.html#method.mine
+
+mod authenticator;
+mod device;
+mod installed;
+mod store;
+
+pub use authenticator::Authenticator;
+pub use device::{DeviceAuthorization, DeviceFlow};
+pub use installed::{InstalledFlow, PendingAuthorization};
+pub use store::{FileTokenStore, MemoryTokenStore, TokenStore};
+
+use std::{fmt, sync::Arc};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use snafu::{ResultExt, Snafu};
+
+use crate::{
+	error::redact,
+	transport::{default_transport, HttpRequest, HttpTransport, Method, TransportError},
+	ApiKey,
+};
+
+/// oauth 2.0 scopes of the youtube data api
+pub mod scope {
+	/// manage the youtube account
+	pub const YOUTUBE: &str = "https://www.googleapis.com/auth/youtube";
+	/// view the youtube account
+	pub const YOUTUBE_READONLY: &str = "https://www.googleapis.com/auth/youtube.readonly";
+	/// see, edit and permanently delete videos, ratings, comments and captions
+	pub const YOUTUBE_FORCE_SSL: &str = "https://www.googleapis.com/auth/youtube.force-ssl";
+	/// manage videos
+	pub const YOUTUBE_UPLOAD: &str = "https://www.googleapis.com/auth/youtube.upload";
+	/// view and manage assets and associated content on youtube
+	pub const YOUTUBEPARTNER: &str = "https://www.googleapis.com/auth/youtubepartner";
+}
+
+/// credentials sent with every request of a [`Client`](../struct.Client.html)
+#[derive(Clone)]
+pub enum Credentials {
+	/// api key sent as the `key` parameter, only grants access to public data
+	ApiKey(ApiKey),
+	/// oauth 2.0 access token sent in the `Authorization` header
+	Bearer(String),
+	/// oauth 2.0 access tokens which are refreshed when they expire
+	Authenticator(Authenticator),
+}
+
+impl Credentials {
+	/// api key to send as the `key` parameter
+	pub(crate) fn api_key(&self) -> Option<&ApiKey> {
+		match self {
+			Self::ApiKey(key) => Some(key),
+			Self::Bearer(_) | Self::Authenticator(_) => None,
+		}
+	}
+
+	/// add the authorization header to `request`
+	pub(crate) async fn authorize(&self, request: HttpRequest) -> Result<HttpRequest, AuthError> {
+		let access_token = match self {
+			Self::ApiKey(_) => return Ok(request),
+			Self::Bearer(access_token) => access_token.clone(),
+			Self::Authenticator(authenticator) => authenticator.token().await?.access_token,
+		};
+		Ok(request.header("authorization", format!("Bearer {}", access_token)))
+	}
+
+	/// refresh the token after `request` was rejected with status 401
+	///
+	/// returns whether sending the request again might succeed.
+	pub(crate) async fn reauthorize(&self, request: &HttpRequest) -> Result<bool, AuthError> {
+		let authenticator = match self {
+			Self::Authenticator(authenticator) => authenticator,
+			Self::ApiKey(_) | Self::Bearer(_) => return Ok(false),
+		};
+		let access_token = request
+			.headers
+			.iter()
+			.find(|(name, _)| *name == "authorization")
+			.and_then(|(_, value)| value.strip_prefix("Bearer "));
+		match access_token {
+			Some(access_token) => authenticator.reject(access_token).await,
+			None => Ok(false),
+		}
+	}
+}
+
+impl From<ApiKey> for Credentials {
+	fn from(key: ApiKey) -> Self {
+		Self::ApiKey(key)
+	}
+}
+
+impl From<Token> for Credentials {
+	fn from(token: Token) -> Self {
+		Self::Bearer(token.access_token)
+	}
+}
+
+impl From<Authenticator> for Credentials {
+	fn from(authenticator: Authenticator) -> Self {
+		Self::Authenticator(authenticator)
+	}
+}
+
+impl fmt::Debug for Credentials {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::ApiKey(_) => f.write_str("ApiKey(REDACTED)"),
+			Self::Bearer(_) => f.write_str("Bearer(REDACTED)"),
+			Self::Authenticator(authenticator) => {
+				f.debug_tuple("Authenticator").field(authenticator).finish()
+			}
+		}
+	}
+}
+
+/// oauth 2.0 tokens granted by the authorization server
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+	pub access_token: String,
+	/// token to get a new access token once it expired, only granted for offline access
+	#[serde(default)]
+	pub refresh_token: Option<String>,
+	#[serde(default)]
+	pub expires_at: Option<DateTime<Utc>>,
+	/// scopes the user granted, which can be less than the requested ones
+	#[serde(default)]
+	pub scopes: Vec<String>,
+}
+
+impl Token {
+	/// whether the access token expires within `margin` from now
+	#[must_use]
+	pub fn expires_within(&self, margin: Duration) -> bool {
+		self.expires_at
+			.is_some_and(|expires_at| expires_at <= Utc::now() + margin)
+	}
+
+	/// whether the access token has expired
+	#[must_use]
+	pub fn is_expired(&self) -> bool {
+		self.expires_within(Duration::zero())
+	}
+}
+
+impl fmt::Debug for Token {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Token")
+			.field("access_token", &"REDACTED")
+			.field(
+				"refresh_token",
+				&self.refresh_token.as_ref().map(|_| "REDACTED"),
+			)
+			.field("expires_at", &self.expires_at)
+			.field("scopes", &self.scopes)
+			.finish()
+	}
+}
+
+/// oauth 2.0 client registered in the google cloud console
+#[derive(Clone)]
+pub struct OAuthClient {
+	client_id: String,
+	client_secret: Option<String>,
+	token_url: String,
+	revoke_url: String,
+	transport: Option<Arc<dyn HttpTransport>>,
+}
+
+impl OAuthClient {
+	const TOKEN_URL: &'static str = "https://oauth2.googleapis.com/token";
+	const REVOKE_URL: &'static str = "https://oauth2.googleapis.com/revoke";
+
+	#[must_use]
+	pub fn new(client_id: impl Into<String>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			client_secret: None,
+			token_url: String::from(Self::TOKEN_URL),
+			revoke_url: String::from(Self::REVOKE_URL),
+			transport: default_transport(),
+		}
+	}
+
+	/// secret of the client, which google also issues to installed applications
+	#[must_use]
+	pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+		self.client_secret = Some(client_secret.into());
+		self
+	}
+
+	/// url of the token endpoint, defaults to the one of google
+	#[must_use]
+	pub fn token_url(mut self, token_url: impl Into<String>) -> Self {
+		self.token_url = token_url.into();
+		self
+	}
+
+	/// url of the revocation endpoint, defaults to the one of google
+	#[must_use]
+	pub fn revoke_url(mut self, revoke_url: impl Into<String>) -> Self {
+		self.revoke_url = revoke_url.into();
+		self
+	}
+
+	/// send the requests to the authorization server through `transport`
+	#[must_use]
+	pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+		self.transport = Some(Arc::new(transport));
+		self
+	}
+
+	#[must_use]
+	pub fn client_id(&self) -> &str {
+		&self.client_id
+	}
+
+	/// post `form` with the client credentials to `url` and parse the json answer
+	pub(crate) async fn post_form<T>(
+		&self,
+		url: &str,
+		form: &[(&str, &str)],
+	) -> Result<T, AuthError>
+	where
+		T: serde::de::DeserializeOwned,
+	{
+		let body = self.post(url, form).await?;
+		// the answer of the token endpoint carries the tokens, so it is left out of the error
+		serde_json::from_str(&body).context(Deserialization)
+	}
+
+	/// post `form` with the client credentials to `url` and return the body of the answer
+	///
+	/// # Panics
+	///
+	/// panics if no transport was set and none is enabled by the cargo features
+	async fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, AuthError> {
+		let transport = self
+			.transport
+			.clone()
+			.expect("no http transport enabled, set one with `OAuthClient::transport`");
+		let mut form = form.to_vec();
+		form.push(("client_id", &self.client_id));
+		if let Some(client_secret) = &self.client_secret {
+			form.push(("client_secret", client_secret));
+		}
+		let body = serde_urlencoded::to_string(&form).context(Encoding)?;
+		let request = HttpRequest::new(Method::Post, url)
+			.header("content-type", "application/x-www-form-urlencoded")
+			.body(body);
+		let response = transport
+			.send(request)
+			.await
+			.context(Connection { url: redact(url) })?;
+		let body = String::from_utf8_lossy(&response.body).into_owned();
+		if !(200..300).contains(&response.status) {
+			return Err(match serde_json::from_str::<ErrorResponse>(&body) {
+				Ok(error) => AuthError::Rejected {
+					error: error.error,
+					description: error.error_description,
+				},
+				Err(_) => AuthError::Http {
+					url: redact(url),
+					status: response.status,
+					body,
+				},
+			});
+		}
+		Ok(body)
+	}
+
+	/// exchange a grant for a token at the token endpoint
+	pub(crate) async fn request_token(&self, form: &[(&str, &str)]) -> Result<Token, AuthError> {
+		let response: TokenResponse = self.post_form(&self.token_url, form).await?;
+		Ok(response.into_token(None))
+	}
+
+	/// get a new access token with `refresh_token`
+	pub(crate) async fn refresh_token(&self, refresh_token: &str) -> Result<Token, AuthError> {
+		let response: TokenResponse = self
+			.post_form(
+				&self.token_url,
+				&[
+					("grant_type", "refresh_token"),
+					("refresh_token", refresh_token),
+				],
+			)
+			.await?;
+		Ok(response.into_token(Some(refresh_token.to_string())))
+	}
+
+	/// revoke an access or refresh token at the revocation endpoint
+	pub(crate) async fn revoke(&self, token: &str) -> Result<(), AuthError> {
+		self.post(&self.revoke_url, &[("token", token)])
+			.await
+			.map(|_| ())
+	}
+}
+
+impl fmt::Debug for OAuthClient {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("OAuthClient")
+			.field("client_id", &self.client_id)
+			.field("token_url", &self.token_url)
+			.field("revoke_url", &self.revoke_url)
+			.finish()
+	}
+}
+
+/// successful answer of the token endpoint
+#[derive(Debug, Deserialize)]
+pub(crate) struct TokenResponse {
+	access_token: String,
+	#[serde(default)]
+	refresh_token: Option<String>,
+	#[serde(default)]
+	expires_in: Option<i64>,
+	#[serde(default)]
+	scope: Option<String>,
+}
+
+impl TokenResponse {
+	/// token with the expiry relative to now, keeping `refresh_token` if no new one was granted
+	pub(crate) fn into_token(self, refresh_token: Option<String>) -> Token {
+		Token {
+			access_token: self.access_token,
+			refresh_token: self.refresh_token.or(refresh_token),
+			expires_at: self
+				.expires_in
+				.map(|expires_in| Utc::now() + Duration::seconds(expires_in)),
+			scopes: self
+				.scope
+				.map(|scope| scope.split(' ').map(String::from).collect())
+				.unwrap_or_default(),
+		}
+	}
+}
+
+/// error answer of the authorization server
+#[derive(Debug, Deserialize)]
+pub(crate) struct ErrorResponse {
+	pub(crate) error: String,
+	#[serde(default)]
+	pub(crate) error_description: Option<String>,
+}
+
+/// error of an oauth 2.0 flow
+#[derive(Debug, Snafu)]
+#[snafu(visibility = "pub(crate)")]
+pub enum AuthError {
+	#[snafu(display("failed to listen for the redirect: {}", source))]
+	Listener { source: std::io::Error },
+	#[snafu(display("the redirect listener stopped without an answer"))]
+	Cancelled,
+	#[snafu(display("the redirect did not carry the state of the request"))]
+	StateMismatch,
+	#[snafu(display("the user code expired before access was granted"))]
+	Expired,
+	#[snafu(display("no token was granted yet"))]
+	NoToken,
+	#[snafu(display("the token expired and no refresh token was granted"))]
+	NoRefreshToken,
+	#[snafu(display("failed to access the token store: {}", source))]
+	Store { source: std::io::Error },
+	#[snafu(display("failed to connect to {}: {}", url, source))]
+	Connection { url: String, source: TransportError },
+	#[snafu(display("{} returned status {}: {}", url, status, body))]
+	Http {
+		url: String,
+		status: u16,
+		body: String,
+	},
+	#[snafu(display(
+		"the authorization server rejected the request: {}{}",
+		error,
+		description.as_ref().map(|description| format!(" ({})", description)).unwrap_or_default()
+	))]
+	Rejected {
+		/// error code like `access_denied` or `invalid_grant`
+		error: String,
+		description: Option<String>,
+	},
+	#[snafu(display("failed to encode the request: {}", source))]
+	Encoding {
+		source: serde_urlencoded::ser::Error,
+	},
+	#[snafu(display(
+		"failed to deserialize the answer of the authorization server: {}",
+		source
+	))]
+	Deserialization { source: serde_json::Error },
+}
+
+impl AuthError {
+	/// oauth 2.0 error code returned by the authorization server, like `access_denied`
+	#[must_use]
+	pub fn error_code(&self) -> Option<&str> {
+		match self {
+			Self::Rejected { error, .. } => Some(error),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,216 @@
+use std::{
+	fmt, fs,
+	io::{self, Write},
+	path::{Path, PathBuf},
+	sync::{Mutex, PoisonError},
+};
+
+use super::Token;
+
+/// persistence of the token of an [`Authenticator`](./struct.Authenticator.html)
+///
+/// the store is written every time a token is granted or refreshed, so a restarted
+/// application can continue without asking the user again.
+pub trait TokenStore: fmt::Debug + Send + Sync {
+	/// stored token, `None` if there is none
+	fn load(&self) -> io::Result<Option<Token>>;
+	/// replace the stored token
+	fn store(&self, token: &Token) -> io::Result<()>;
+	/// remove the stored token
+	fn clear(&self) -> io::Result<()>;
+}
+
+/// store keeping the token in memory for the lifetime of the process
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+	token: Mutex<Option<Token>>,
+}
+
+impl MemoryTokenStore {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl TokenStore for MemoryTokenStore {
+	fn load(&self) -> io::Result<Option<Token>> {
+		Ok(self
+			.token
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.clone())
+	}
+
+	fn store(&self, token: &Token) -> io::Result<()> {
+		*self.token.lock().unwrap_or_else(PoisonError::into_inner) = Some(token.clone());
+		Ok(())
+	}
+
+	fn clear(&self) -> io::Result<()> {
+		*self.token.lock().unwrap_or_else(PoisonError::into_inner) = None;
+		Ok(())
+	}
+}
+
+/// store keeping the token as json in a file only readable by the current user
+///
+/// the token is written to a temporary file next to `path` which then replaces the previous
+/// file, so a crash never leaves a truncated token behind.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+	path: PathBuf,
+}
+
+impl FileTokenStore {
+	#[must_use]
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self { path: path.into() }
+	}
+
+	#[must_use]
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	#[cfg(unix)]
+	fn create(path: &Path) -> io::Result<fs::File> {
+		use std::os::unix::fs::OpenOptionsExt;
+
+		fs::OpenOptions::new()
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.mode(0o600)
+			.open(path)
+	}
+
+	#[cfg(not(unix))]
+	fn create(path: &Path) -> io::Result<fs::File> {
+		fs::File::create(path)
+	}
+}
+
+impl TokenStore for FileTokenStore {
+	fn load(&self) -> io::Result<Option<Token>> {
+		match fs::read(&self.path) {
+			Ok(json) => serde_json::from_slice(&json)
+				.map(Some)
+				.map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+			Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+			Err(error) => Err(error),
+		}
+	}
+
+	fn store(&self, token: &Token) -> io::Result<()> {
+		let json = serde_json::to_vec_pretty(token)?;
+		let mut temporary = self.path.clone().into_os_string();
+		temporary.push(".tmp");
+		let temporary = PathBuf::from(temporary);
+		// a leftover file would keep its permissions, so start from scratch
+		let _ = fs::remove_file(&temporary);
+		let mut file = Self::create(&temporary)?;
+		file.write_all(&json)?;
+		file.sync_all()?;
+		fs::rename(&temporary, &self.path)
+	}
+
+	fn clear(&self) -> io::Result<()> {
+		match fs::remove_file(&self.path) {
+			Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+			_ => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs, path::PathBuf, process};
+
+	use super::{FileTokenStore, TokenStore};
+	use crate::auth::Token;
+
+	/// empty directory for one test, removed when dropped
+	struct TempDir(PathBuf);
+
+	impl TempDir {
+		fn new(name: &str) -> Self {
+			let path = env::temp_dir().join(format!("yt-api-{}-{}", name, process::id()));
+			let _ = fs::remove_dir_all(&path);
+			fs::create_dir_all(&path).unwrap();
+			Self(path)
+		}
+	}
+
+	impl Drop for TempDir {
+		fn drop(&mut self) {
+			let _ = fs::remove_dir_all(&self.0);
+		}
+	}
+
+	fn token() -> Token {
+		Token {
+			access_token: String::from("access"),
+			refresh_token: Some(String::from("refresh")),
+			expires_at: Some("2020-01-01T12:00:00Z".parse().unwrap()),
+			scopes: vec![String::from("scope")],
+		}
+	}
+
+	#[test]
+	fn round_trips_the_token() {
+		let directory = TempDir::new("round-trip");
+		let store = FileTokenStore::new(directory.0.join("token.json"));
+
+		assert_eq!(store.load().unwrap(), None);
+		store.store(&token()).unwrap();
+		assert_eq!(store.load().unwrap(), Some(token()));
+
+		let mut refreshed = token();
+		refreshed.access_token = String::from("refreshed");
+		store.store(&refreshed).unwrap();
+		assert_eq!(store.load().unwrap(), Some(refreshed));
+		assert_eq!(
+			fs::read_dir(&directory.0).unwrap().count(),
+			1,
+			"the temporary file was not renamed"
+		);
+
+		store.clear().unwrap();
+		assert_eq!(store.load().unwrap(), None);
+		store.clear().unwrap();
+	}
+
+	#[test]
+	fn replaces_a_leftover_temporary_file() {
+		let directory = TempDir::new("leftover");
+		let path = directory.0.join("token.json");
+		fs::write(directory.0.join("token.json.tmp"), "truncated").unwrap();
+
+		FileTokenStore::new(&path).store(&token()).unwrap();
+
+		assert!(!directory.0.join("token.json.tmp").exists());
+		assert_eq!(FileTokenStore::new(path).load().unwrap(), Some(token()));
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn writes_the_file_only_readable_by_the_owner() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let directory = TempDir::new("mode");
+		let path = directory.0.join("token.json");
+		// a leftover temporary file with other permissions must not be reused
+		fs::write(directory.0.join("token.json.tmp"), "").unwrap();
+		fs::set_permissions(
+			directory.0.join("token.json.tmp"),
+			fs::Permissions::from_mode(0o644),
+		)
+		.unwrap();
+
+		FileTokenStore::new(&path).store(&token()).unwrap();
+
+		let mode = fs::metadata(&path).unwrap().permissions().mode();
+		assert_eq!(mode & 0o777, 0o600);
+	}
+}
@@ -0,0 +1,469 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	error::Validation,
+	fields::{Schema, LEAF},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for listing the caption tracks of a video
+pub struct CaptionsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<CaptionsListData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CaptionsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<CaptionPart>,
+	video_id: String,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+}
+
+impl CaptionsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>, video_id: impl Into<String>) -> Self {
+		Self::with_client(Client::new(credentials), video_id)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client, video_id: impl Into<String>) -> Self {
+		Self {
+			future: None,
+			data: Some(CaptionsListData {
+				fields: None,
+				part: vec![CaptionPart::Snippet],
+				video_id: video_id.into(),
+				id: Vec::new(),
+				on_behalf_of: None,
+				on_behalf_of_content_owner: None,
+			}),
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = CaptionPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// only get the tracks with these ids
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of(mut self, on_behalf_of: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of = Some(on_behalf_of.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for CaptionsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for CaptionsListData {
+	const ENDPOINT: Endpoint = Endpoint::CaptionsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		ensure!(
+			!self.video_id.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "videoId must not be empty",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, _page_token: String) {}
+}
+
+/// request struct for downloading a caption track
+///
+/// the owner of the video has to authorize the request unless third party contributions
+/// are enabled for the track.
+pub struct CaptionsDownload {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<CaptionFile, Error>>>,
+	id: String,
+	data: Option<CaptionsDownloadData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CaptionsDownloadData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	tfmt: Option<CaptionFormat>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	tlang: Option<String>,
+}
+
+impl CaptionsDownload {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>, id: impl Into<String>) -> Self {
+		Self::with_client(Client::new(credentials), id)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client, id: impl Into<String>) -> Self {
+		Self {
+			future: None,
+			id: id.into(),
+			data: Some(CaptionsDownloadData {
+				on_behalf_of_content_owner: None,
+				tfmt: None,
+				tlang: None,
+			}),
+			client,
+		}
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// convert the track to this format, defaults to the format it was uploaded in
+	#[must_use]
+	pub fn tfmt(mut self, tfmt: impl Into<CaptionFormat>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.tfmt = Some(tfmt.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// translate the track to the language with the code `tlang`
+	#[must_use]
+	pub fn tlang(mut self, tlang: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.tlang = Some(tlang.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for CaptionsDownload {
+	type Output = Result<CaptionFile, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let id = self.id.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(download(client, id, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+async fn download(
+	client: Client,
+	id: String,
+	data: CaptionsDownloadData,
+) -> Result<CaptionFile, Error> {
+	let endpoint = Endpoint::CaptionsDownload;
+	ensure!(
+		!id.is_empty(),
+		Validation {
+			endpoint,
+			message: "the id of the caption track must not be empty",
+		}
+	);
+	ensure!(
+		data.tlang.as_ref().is_none_or(|tlang| !tlang.is_empty()),
+		Validation {
+			endpoint,
+			message: "tlang must not be empty",
+		}
+	);
+	let path = format!("{}/{}", endpoint.path(), params::path_segment(&id));
+	let response = client.get_raw(endpoint, &path, &data).await?;
+	let content_type = response.get_header("content-type").map(String::from);
+	let format = data
+		.tfmt
+		.or_else(|| CaptionFormat::detect(content_type.as_deref(), &response.body));
+	Ok(CaptionFile {
+		format,
+		content_type,
+		data: response.body,
+	})
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	(
+		"items",
+		&Schema(&[
+			("kind", &LEAF),
+			("etag", &LEAF),
+			("id", &LEAF),
+			(
+				"snippet",
+				&Schema(&[
+					("videoId", &LEAF),
+					("lastUpdated", &LEAF),
+					("trackKind", &LEAF),
+					("language", &LEAF),
+					("name", &LEAF),
+					("audioTrackType", &LEAF),
+					("isCC", &LEAF),
+					("isLarge", &LEAF),
+					("isEasyReader", &LEAF),
+					("isDraft", &LEAF),
+					("isAutoSynced", &LEAF),
+					("status", &LEAF),
+					("failureReason", &LEAF),
+				]),
+			),
+		]),
+	),
+]);
+
+/// resource part which can be requested with [`part`](./struct.CaptionsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaptionPart {
+	Id,
+	Snippet,
+}
+
+/// file format of a caption track
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptionFormat {
+	/// SubViewer
+	Sbv,
+	/// Scenarist Closed Caption
+	Scc,
+	/// SubRip
+	Srt,
+	/// Timed Text Markup Language
+	Ttml,
+	/// Web Video Text Tracks
+	Vtt,
+}
+
+impl CaptionFormat {
+	/// guess the format of a downloaded track from its content, falling back to its content type
+	#[must_use]
+	pub fn detect(content_type: Option<&str>, data: &[u8]) -> Option<Self> {
+		let text = String::from_utf8_lossy(&data[..data.len().min(512)]);
+		let text = text.trim_start_matches('\u{feff}').trim_start();
+		let first_line = text.lines().next().unwrap_or_default();
+		let second_line = text.lines().nth(1).unwrap_or_default();
+		if text.starts_with("WEBVTT") {
+			Some(Self::Vtt)
+		} else if text.starts_with("Scenarist_SCC") {
+			Some(Self::Scc)
+		} else if text.starts_with("<?xml") || text.starts_with("<tt") {
+			Some(Self::Ttml)
+		} else if first_line.trim().parse::<u32>().is_ok() && second_line.contains("-->") {
+			Some(Self::Srt)
+		} else if first_line.contains(':') && first_line.contains(',') {
+			Some(Self::Sbv)
+		} else {
+			let content_type = content_type?.split(';').next()?.trim();
+			match content_type {
+				"text/vtt" => Some(Self::Vtt),
+				"application/x-subrip" | "text/srt" => Some(Self::Srt),
+				"application/ttml+xml" | "text/xml" | "application/xml" => Some(Self::Ttml),
+				"text/sbv" => Some(Self::Sbv),
+				"text/scc" => Some(Self::Scc),
+				_ => None,
+			}
+		}
+	}
+}
+
+/// downloaded caption track
+#[derive(Debug, Clone)]
+pub struct CaptionFile {
+	/// requested format, or the format detected from the content
+	pub format: Option<CaptionFormat>,
+	/// content type sent by the server
+	pub content_type: Option<String>,
+	pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub items: Vec<Caption>,
+}
+
+/// metadata of a caption track, the snippet is only set when it was requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Caption {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub video_id: Option<String>,
+	pub last_updated: Option<DateTime<Utc>>,
+	pub track_kind: Option<TrackKind>,
+	/// BCP-47 language tag like `en` or `pt-BR`
+	pub language: Option<String>,
+	pub name: Option<String>,
+	pub audio_track_type: Option<String>,
+	#[serde(rename = "isCC")]
+	pub is_cc: Option<bool>,
+	pub is_large: Option<bool>,
+	pub is_easy_reader: Option<bool>,
+	/// drafts are not visible to viewers
+	pub is_draft: Option<bool>,
+	pub is_auto_synced: Option<bool>,
+	pub status: Option<TrackStatus>,
+	pub failure_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackKind {
+	/// generated by automatic speech recognition
+	#[serde(alias = "ASR")]
+	Asr,
+	/// only shown for foreign language parts of the video
+	Forced,
+	Standard,
+	/// track kind this crate does not know about
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackStatus {
+	Failed,
+	Serving,
+	Syncing,
+	/// status this crate does not know about
+	#[serde(other)]
+	Other,
+}
+
+impl Page for Response {
+	type Item = Caption;
+
+	fn next_page_token(&self) -> Option<&str> {
+		None
+	}
+
+	fn into_items(self) -> Vec<Caption> {
+		self.items
+	}
+}
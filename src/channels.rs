@@ -0,0 +1,557 @@
+use std::{
+	collections::HashMap,
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::{Localization, PageInfo, Thumbnails, LOCALIZATION},
+	de,
+	error::Validation,
+	fields::{Schema, ANY, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the channels endpoint
+///
+/// exactly one of [`id`](#method.id), [`for_handle`](#method.for_handle),
+/// [`for_username`](#method.for_username), [`mine`](#method.mine),
+/// [`managed_by_me`](#method.managed_by_me) and [`category_id`](#method.category_id) has to be
+/// set.
+pub struct ChannelsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<ChannelsListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChannelsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<ChannelPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	category_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	for_handle: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	for_username: Option<String>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	managed_by_me: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	mine: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hl: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+}
+
+impl ChannelsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(ChannelsListData {
+				fields: None,
+				part: vec![ChannelPart::Snippet],
+				category_id: None,
+				for_handle: None,
+				for_username: None,
+				id: Vec::new(),
+				managed_by_me: None,
+				mine: None,
+				hl: client.hl().cloned(),
+				max_results: None,
+				on_behalf_of_content_owner: None,
+				page_token: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = ChannelPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the channels of a guide category
+	#[must_use]
+	pub fn category_id(mut self, category_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.category_id = Some(category_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// get the channel with a handle like `@youtube`, the `@` is optional
+	#[must_use]
+	pub fn for_handle(mut self, for_handle: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.for_handle = Some(for_handle.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// get the channel of a legacy youtube username
+	#[must_use]
+	pub fn for_username(mut self, for_username: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.for_username = Some(for_username.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the channels to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the channels managed by the authorized content owner
+	#[must_use]
+	pub fn managed_by_me(mut self, managed_by_me: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.managed_by_me = Some(managed_by_me);
+		self.data = Some(data);
+		self
+	}
+
+	/// get the channels of the authorized user
+	#[must_use]
+	pub fn mine(mut self, mine: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.mine = Some(mine);
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.hl = Some(hl.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<Channel, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for ChannelsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for ChannelsListData {
+	const ENDPOINT: Endpoint = Endpoint::ChannelsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		let filters = [
+			self.category_id.is_some(),
+			self.for_handle.is_some(),
+			self.for_username.is_some(),
+			!self.id.is_empty(),
+			self.managed_by_me.is_some(),
+			self.mine.is_some(),
+		];
+		ensure!(
+			filters.iter().filter(|set| **set).count() == 1,
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of categoryId, forHandle, forUsername, id, managedByMe and \
+				          mine must be set",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &CHANNEL_SCHEMA),
+]);
+
+static CHANNEL_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("title", &LEAF),
+			("description", &LEAF),
+			("customUrl", &LEAF),
+			("publishedAt", &LEAF),
+			("thumbnails", &THUMBNAILS),
+			("defaultLanguage", &LEAF),
+			("localized", &LOCALIZATION),
+			("country", &LEAF),
+		]),
+	),
+	(
+		"contentDetails",
+		&Schema(&[(
+			"relatedPlaylists",
+			&Schema(&[("likes", &LEAF), ("favorites", &LEAF), ("uploads", &LEAF)]),
+		)]),
+	),
+	(
+		"statistics",
+		&Schema(&[
+			("viewCount", &LEAF),
+			("subscriberCount", &LEAF),
+			("hiddenSubscriberCount", &LEAF),
+			("videoCount", &LEAF),
+		]),
+	),
+	(
+		"topicDetails",
+		&Schema(&[("topicIds", &LEAF), ("topicCategories", &LEAF)]),
+	),
+	(
+		"status",
+		&Schema(&[
+			("privacyStatus", &LEAF),
+			("isLinked", &LEAF),
+			("longUploadsStatus", &LEAF),
+			("madeForKids", &LEAF),
+			("selfDeclaredMadeForKids", &LEAF),
+		]),
+	),
+	(
+		"brandingSettings",
+		&Schema(&[
+			(
+				"channel",
+				&Schema(&[
+					("title", &LEAF),
+					("description", &LEAF),
+					("keywords", &LEAF),
+					("trackingAnalyticsAccountId", &LEAF),
+					("unsubscribedTrailer", &LEAF),
+					("defaultLanguage", &LEAF),
+					("country", &LEAF),
+				]),
+			),
+			("watch", &ANY),
+			("image", &ANY),
+		]),
+	),
+	(
+		"auditDetails",
+		&Schema(&[
+			("overallGoodStanding", &LEAF),
+			("communityGuidelinesGoodStanding", &LEAF),
+			("copyrightStrikesGoodStanding", &LEAF),
+			("contentIdClaimsGoodStanding", &LEAF),
+		]),
+	),
+	(
+		"contentOwnerDetails",
+		&Schema(&[("contentOwner", &LEAF), ("timeLinked", &LEAF)]),
+	),
+	("localizations", &ANY),
+]);
+
+/// resource part which can be requested with [`part`](./struct.ChannelsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChannelPart {
+	AuditDetails,
+	BrandingSettings,
+	ContentDetails,
+	ContentOwnerDetails,
+	Id,
+	Localizations,
+	Snippet,
+	Statistics,
+	Status,
+	TopicDetails,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub prev_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<Channel>,
+}
+
+/// channel resource, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Channel {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub content_details: Option<ContentDetails>,
+	pub statistics: Option<Statistics>,
+	pub topic_details: Option<TopicDetails>,
+	pub status: Option<Status>,
+	pub branding_settings: Option<BrandingSettings>,
+	pub audit_details: Option<AuditDetails>,
+	pub content_owner_details: Option<ContentOwnerDetails>,
+	/// translations of the title and description keyed by language
+	pub localizations: Option<HashMap<String, Localization>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub title: Option<String>,
+	pub description: Option<String>,
+	/// handle of the channel like `@youtube`
+	pub custom_url: Option<String>,
+	pub published_at: Option<DateTime<Utc>>,
+	pub thumbnails: Option<Thumbnails>,
+	pub default_language: Option<String>,
+	/// title and description in the language requested with `hl`
+	pub localized: Option<Localization>,
+	pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentDetails {
+	pub related_playlists: RelatedPlaylists,
+}
+
+/// ids of the playlists youtube keeps for every channel
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RelatedPlaylists {
+	pub likes: Option<String>,
+	pub favorites: Option<String>,
+	/// playlist of all uploaded videos, can be listed with
+	/// [`PlaylistItems`](../playlistitems/struct.PlaylistItems.html)
+	pub uploads: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Statistics {
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub view_count: Option<u64>,
+	/// rounded down to three significant figures, unset when hidden
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub subscriber_count: Option<u64>,
+	pub hidden_subscriber_count: Option<bool>,
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub video_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TopicDetails {
+	pub topic_ids: Vec<String>,
+	/// wikipedia urls describing the content of the channel
+	pub topic_categories: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Status {
+	pub privacy_status: Option<String>,
+	pub is_linked: Option<bool>,
+	pub long_uploads_status: Option<String>,
+	pub made_for_kids: Option<bool>,
+	pub self_declared_made_for_kids: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BrandingSettings {
+	pub channel: Option<ChannelSettings>,
+	pub watch: Option<HashMap<String, serde_json::Value>>,
+	pub image: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChannelSettings {
+	pub title: Option<String>,
+	pub description: Option<String>,
+	/// space separated keywords, multiple words are put in quotes
+	pub keywords: Option<String>,
+	pub tracking_analytics_account_id: Option<String>,
+	/// video id of the trailer shown to unsubscribed viewers
+	pub unsubscribed_trailer: Option<String>,
+	pub default_language: Option<String>,
+	pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AuditDetails {
+	pub overall_good_standing: Option<bool>,
+	pub community_guidelines_good_standing: Option<bool>,
+	pub copyright_strikes_good_standing: Option<bool>,
+	pub content_id_claims_good_standing: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentOwnerDetails {
+	pub content_owner: Option<String>,
+	pub time_linked: Option<DateTime<Utc>>,
+}
+
+impl Page for Response {
+	type Item = Channel;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<Channel> {
+		self.items
+	}
+}
@@ -0,0 +1,302 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	error::Validation,
+	fields::{Schema, LEAF},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the channelSections endpoint
+///
+/// exactly one of [`channel_id`](#method.channel_id), [`id`](#method.id) and
+/// [`mine`](#method.mine) has to be set.
+pub struct ChannelSectionsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<ChannelSectionsListData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChannelSectionsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<ChannelSectionPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	channel_id: Option<String>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	mine: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hl: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+}
+
+impl ChannelSectionsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(ChannelSectionsListData {
+				fields: None,
+				part: vec![ChannelSectionPart::Snippet],
+				channel_id: None,
+				id: Vec::new(),
+				mine: None,
+				hl: client.hl().cloned(),
+				on_behalf_of_content_owner: None,
+			}),
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = ChannelSectionPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the sections of a channel
+	#[must_use]
+	pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.channel_id = Some(channel_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the sections to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the sections of the channel of the authorized user
+	#[must_use]
+	pub fn mine(mut self, mine: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.mine = Some(mine);
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.hl = Some(hl.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for ChannelSectionsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for ChannelSectionsListData {
+	const ENDPOINT: Endpoint = Endpoint::ChannelSectionsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		let filters = [
+			self.channel_id.is_some(),
+			!self.id.is_empty(),
+			self.mine.is_some(),
+		];
+		ensure!(
+			filters.iter().filter(|set| **set).count() == 1,
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of channelId, id and mine must be set",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, _page_token: String) {}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	(
+		"items",
+		&Schema(&[
+			("kind", &LEAF),
+			("etag", &LEAF),
+			("id", &LEAF),
+			(
+				"snippet",
+				&Schema(&[
+					("type", &LEAF),
+					("channelId", &LEAF),
+					("title", &LEAF),
+					("position", &LEAF),
+				]),
+			),
+			(
+				"contentDetails",
+				&Schema(&[("playlists", &LEAF), ("channels", &LEAF)]),
+			),
+		]),
+	),
+]);
+
+/// resource part which can be requested with
+/// [`part`](./struct.ChannelSectionsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChannelSectionPart {
+	ContentDetails,
+	Id,
+	Snippet,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub items: Vec<ChannelSection>,
+}
+
+/// section of the home page of a channel, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChannelSection {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub content_details: Option<ContentDetails>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	#[serde(rename = "type")]
+	pub section_type: Option<SectionType>,
+	pub channel_id: Option<String>,
+	/// only set for sections of the `multiplePlaylists` and `multipleChannels` types
+	pub title: Option<String>,
+	/// position of the section on the channel page, starting at 0
+	pub position: Option<u32>,
+}
+
+/// kind of content shown in a section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SectionType {
+	AllPlaylists,
+	CompletedEvents,
+	LiveEvents,
+	MultipleChannels,
+	MultiplePlaylists,
+	PopularUploads,
+	RecentUploads,
+	SinglePlaylist,
+	Subscriptions,
+	UpcomingEvents,
+	/// section type this crate does not know about
+	#[serde(other)]
+	Other,
+}
+
+/// resources shown in a section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ContentDetails {
+	/// playlist ids for the `singlePlaylist` and `multiplePlaylists` types
+	pub playlists: Vec<String>,
+	/// channel ids for the `multipleChannels` type
+	pub channels: Vec<String>,
+}
+
+impl Page for Response {
+	type Item = ChannelSection;
+
+	fn next_page_token(&self) -> Option<&str> {
+		None
+	}
+
+	fn into_items(self) -> Vec<ChannelSection> {
+		self.items
+	}
+}
@@ -0,0 +1,648 @@
+use std::{fmt, sync::Arc, time::Instant};
+
+use futures_timer::Delay;
+use log::{debug, warn};
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::ResultExt;
+
+use crate::{
+	activities::ActivitiesList,
+	auth::Credentials,
+	captions::{CaptionsDownload, CaptionsList},
+	channels::ChannelsList,
+	channelsections::ChannelSectionsList,
+	comments::CommentsList,
+	commentthreads::CommentThreadsList,
+	error::{redact, Authentication, Connection, Deserialization, Encoding, Serialization},
+	livebroadcasts::LiveBroadcastsList,
+	livestreams::LiveStreamsList,
+	playlistitems::{PlaylistItems, PlaylistItemsDelete, PlaylistItemsInsert, PlaylistItemsUpdate},
+	playlists::{PlaylistsDelete, PlaylistsInsert, PlaylistsList, PlaylistsUpdate},
+	reference::{self, I18nLanguagesList, I18nRegionsList, Reference, VideoCategoriesList},
+	search::SearchList,
+	subscriptions::SubscriptionsList,
+	transport::{default_transport, HttpRequest, HttpResponse, HttpTransport, Method},
+	videos::VideosList,
+	ApiError, ApiKey, Endpoint, Error, QuotaTracker, RetryPolicy,
+};
+
+/// shared client holding the credentials, the http connection pool and the default
+/// parameters sent with every request
+///
+/// cloning a `Client` is cheap, all clones share the same connection pool.
+#[derive(Clone)]
+pub struct Client {
+	inner: Arc<Inner>,
+}
+
+#[derive(Clone)]
+struct Inner {
+	base_url: String,
+	parameters: SystemParameters,
+	hl: Option<String>,
+	region_code: Option<String>,
+	retry_policy: RetryPolicy,
+	quota: Arc<QuotaTracker>,
+	transport: Arc<dyn HttpTransport>,
+	credentials: Credentials,
+	reference: Arc<reference::Cache>,
+}
+
+/// parameters which are understood by every endpoint of the api
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SystemParameters {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	key: Option<ApiKey>,
+	pretty_print: bool,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	quota_user: Option<String>,
+}
+
+impl Client {
+	const ROOT_URL: &'static str = "https://www.googleapis.com/";
+	const SERVICE_PATH: &'static str = "youtube/v3/";
+
+	/// create a client with [`Credentials`](./auth/enum.Credentials.html), like an
+	/// [`ApiKey`](./struct.ApiKey.html) or an oauth 2.0 token, and the default settings
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::builder(credentials).build()
+	}
+
+	/// create a [`ClientBuilder`](./struct.ClientBuilder.html) to configure the defaults
+	#[must_use]
+	pub fn builder(credentials: impl Into<Credentials>) -> ClientBuilder {
+		ClientBuilder {
+			credentials: credentials.into(),
+			root_url: String::from(Self::ROOT_URL),
+			service_path: String::from(Self::SERVICE_PATH),
+			hl: None,
+			region_code: None,
+			pretty_print: false,
+			quota_user: None,
+			retry_policy: RetryPolicy::default(),
+			quota: None,
+			transport: None,
+		}
+	}
+
+	/// create a [`SearchList`](./search/struct.SearchList.html) request using this client
+	#[must_use]
+	pub fn search(&self) -> SearchList {
+		SearchList::with_client(self.clone())
+	}
+
+	/// create a [`PlaylistItems`](./playlistitems/struct.PlaylistItems.html) request using this
+	/// client
+	#[must_use]
+	pub fn playlist_items(&self) -> PlaylistItems {
+		PlaylistItems::with_client(self.clone())
+	}
+
+	/// create a [`PlaylistItemsInsert`](./playlistitems/struct.PlaylistItemsInsert.html)
+	/// request adding the video `video_id` to the playlist `playlist_id` using this client
+	#[must_use]
+	pub fn insert_playlist_item(
+		&self,
+		playlist_id: impl Into<String>,
+		video_id: impl Into<String>,
+	) -> PlaylistItemsInsert {
+		PlaylistItemsInsert::with_client(self.clone(), playlist_id, video_id)
+	}
+
+	/// create a [`PlaylistItemsUpdate`](./playlistitems/struct.PlaylistItemsUpdate.html)
+	/// request for the item `id` using this client
+	#[must_use]
+	pub fn update_playlist_item(
+		&self,
+		id: impl Into<String>,
+		playlist_id: impl Into<String>,
+		video_id: impl Into<String>,
+	) -> PlaylistItemsUpdate {
+		PlaylistItemsUpdate::with_client(self.clone(), id, playlist_id, video_id)
+	}
+
+	/// create a [`PlaylistItemsDelete`](./playlistitems/struct.PlaylistItemsDelete.html)
+	/// request for the item `id` using this client
+	#[must_use]
+	pub fn delete_playlist_item(&self, id: impl Into<String>) -> PlaylistItemsDelete {
+		PlaylistItemsDelete::with_client(self.clone(), id)
+	}
+
+	/// create a [`VideosList`](./videos/struct.VideosList.html) request using this client
+	#[must_use]
+	pub fn videos(&self) -> VideosList {
+		VideosList::with_client(self.clone())
+	}
+
+	/// create a [`ChannelsList`](./channels/struct.ChannelsList.html) request using this client
+	#[must_use]
+	pub fn channels(&self) -> ChannelsList {
+		ChannelsList::with_client(self.clone())
+	}
+
+	/// create a [`PlaylistsList`](./playlists/struct.PlaylistsList.html) request using this client
+	#[must_use]
+	pub fn playlists(&self) -> PlaylistsList {
+		PlaylistsList::with_client(self.clone())
+	}
+
+	/// create a [`PlaylistsInsert`](./playlists/struct.PlaylistsInsert.html) request creating
+	/// a playlist called `title` using this client
+	#[must_use]
+	pub fn insert_playlist(&self, title: impl Into<String>) -> PlaylistsInsert {
+		PlaylistsInsert::with_client(self.clone(), title)
+	}
+
+	/// create a [`PlaylistsUpdate`](./playlists/struct.PlaylistsUpdate.html) request for the
+	/// playlist `id` using this client
+	#[must_use]
+	pub fn update_playlist(
+		&self,
+		id: impl Into<String>,
+		title: impl Into<String>,
+	) -> PlaylistsUpdate {
+		PlaylistsUpdate::with_client(self.clone(), id, title)
+	}
+
+	/// create a [`PlaylistsDelete`](./playlists/struct.PlaylistsDelete.html) request for the
+	/// playlist `id` using this client
+	#[must_use]
+	pub fn delete_playlist(&self, id: impl Into<String>) -> PlaylistsDelete {
+		PlaylistsDelete::with_client(self.clone(), id)
+	}
+
+	/// create a [`CommentsList`](./comments/struct.CommentsList.html) request using this client
+	#[must_use]
+	pub fn comments(&self) -> CommentsList {
+		CommentsList::with_client(self.clone())
+	}
+
+	/// create a [`CommentThreadsList`](./commentthreads/struct.CommentThreadsList.html) request using this client
+	#[must_use]
+	pub fn comment_threads(&self) -> CommentThreadsList {
+		CommentThreadsList::with_client(self.clone())
+	}
+
+	/// create a [`SubscriptionsList`](./subscriptions/struct.SubscriptionsList.html) request using this client
+	#[must_use]
+	pub fn subscriptions(&self) -> SubscriptionsList {
+		SubscriptionsList::with_client(self.clone())
+	}
+
+	/// create a [`ActivitiesList`](./activities/struct.ActivitiesList.html) request using this client
+	#[must_use]
+	pub fn activities(&self) -> ActivitiesList {
+		ActivitiesList::with_client(self.clone())
+	}
+
+	/// create a [`VideoCategoriesList`](./reference/struct.VideoCategoriesList.html) request using this client
+	#[must_use]
+	pub fn video_categories(&self) -> VideoCategoriesList {
+		VideoCategoriesList::with_client(self.clone())
+	}
+
+	/// create an [`I18nLanguagesList`](./reference/struct.I18nLanguagesList.html) request using
+	/// this client
+	#[must_use]
+	pub fn i18n_languages(&self) -> I18nLanguagesList {
+		I18nLanguagesList::with_client(self.clone())
+	}
+
+	/// create an [`I18nRegionsList`](./reference/struct.I18nRegionsList.html) request using this
+	/// client
+	#[must_use]
+	pub fn i18n_regions(&self) -> I18nRegionsList {
+		I18nRegionsList::with_client(self.clone())
+	}
+
+	/// reference data cached for the life of this client and its clones
+	#[must_use]
+	pub fn reference(&self) -> Reference {
+		Reference::new(self.clone())
+	}
+
+	/// create a [`ChannelSectionsList`](./channelsections/struct.ChannelSectionsList.html) request using this client
+	#[must_use]
+	pub fn channel_sections(&self) -> ChannelSectionsList {
+		ChannelSectionsList::with_client(self.clone())
+	}
+
+	/// create a [`CaptionsList`](./captions/struct.CaptionsList.html) request for the caption
+	/// tracks of `video_id` using this client
+	#[must_use]
+	pub fn captions(&self, video_id: impl Into<String>) -> CaptionsList {
+		CaptionsList::with_client(self.clone(), video_id)
+	}
+
+	/// create a [`CaptionsDownload`](./captions/struct.CaptionsDownload.html) request for the
+	/// caption track `id` using this client
+	#[must_use]
+	pub fn download_caption(&self, id: impl Into<String>) -> CaptionsDownload {
+		CaptionsDownload::with_client(self.clone(), id)
+	}
+
+	/// create a [`LiveBroadcastsList`](./livebroadcasts/struct.LiveBroadcastsList.html) request using this client
+	#[must_use]
+	pub fn live_broadcasts(&self) -> LiveBroadcastsList {
+		LiveBroadcastsList::with_client(self.clone())
+	}
+
+	/// create a [`LiveStreamsList`](./livestreams/struct.LiveStreamsList.html) request using this client
+	#[must_use]
+	pub fn live_streams(&self) -> LiveStreamsList {
+		LiveStreamsList::with_client(self.clone())
+	}
+
+	/// default language for requests supporting the `hl` parameter
+	#[must_use]
+	pub fn hl(&self) -> Option<&String> {
+		self.inner.hl.as_ref()
+	}
+
+	/// default region for requests supporting the `regionCode` parameter
+	#[must_use]
+	pub fn region_code(&self) -> Option<&String> {
+		self.inner.region_code.as_ref()
+	}
+
+	/// url all endpoint paths are appended to, made of the root url and the service path
+	#[must_use]
+	pub fn base_url(&self) -> &str {
+		&self.inner.base_url
+	}
+
+	/// policy used to retry failed requests
+	#[must_use]
+	pub fn retry_policy(&self) -> &RetryPolicy {
+		&self.inner.retry_policy
+	}
+
+	/// tracker of the quota units spent by this client
+	#[must_use]
+	pub fn quota(&self) -> &QuotaTracker {
+		&self.inner.quota
+	}
+
+	/// reference data shared by all clones of this client
+	pub(crate) fn reference_cache(&self) -> &reference::Cache {
+		&self.inner.reference
+	}
+
+	/// copy of this client sharing the connection pool but using another retry policy
+	pub(crate) fn with_retry_policy(&self, retry_policy: RetryPolicy) -> Self {
+		let mut inner = Inner::clone(&self.inner);
+		inner.retry_policy = retry_policy;
+		Self {
+			inner: Arc::new(inner),
+		}
+	}
+
+	/// send a get request to `endpoint` with `query` and deserialize the response
+	///
+	/// failed attempts are retried according to the retry policy.
+	pub(crate) async fn get<T: DeserializeOwned>(
+		&self,
+		endpoint: Endpoint,
+		query: &(impl Serialize + Sync),
+	) -> Result<T, Error> {
+		let url = self
+			.url(endpoint.path(), query)
+			.context(Serialization { endpoint })?;
+		let redacted = redact(&url);
+		let HttpResponse { body, .. } = self
+			.send(endpoint, HttpRequest::new(Method::Get, url))
+			.await?;
+		Self::parse(endpoint, redacted, &body)
+	}
+
+	/// send `body` as json with `method` to `endpoint` with `query` and deserialize the response
+	pub(crate) async fn send_json<T: DeserializeOwned>(
+		&self,
+		endpoint: Endpoint,
+		method: Method,
+		query: &(impl Serialize + Sync),
+		body: &(impl Serialize + Sync),
+	) -> Result<T, Error> {
+		let url = self
+			.url(endpoint.path(), query)
+			.context(Serialization { endpoint })?;
+		let redacted = redact(&url);
+		let body = serde_json::to_vec(body).context(Encoding { endpoint })?;
+		let request = HttpRequest::new(method, url)
+			.header("content-type", "application/json")
+			.body(body);
+		let HttpResponse { body, .. } = self.send(endpoint, request).await?;
+		Self::parse(endpoint, redacted, &body)
+	}
+
+	/// send a delete request to `endpoint` with `query`
+	pub(crate) async fn delete(
+		&self,
+		endpoint: Endpoint,
+		query: &(impl Serialize + Sync),
+	) -> Result<(), Error> {
+		let url = self
+			.url(endpoint.path(), query)
+			.context(Serialization { endpoint })?;
+		self.send(endpoint, HttpRequest::new(Method::Delete, url))
+			.await
+			.map(|_| ())
+	}
+
+	/// deserialize the json `body` of a response from `url`
+	fn parse<T: DeserializeOwned>(
+		endpoint: Endpoint,
+		url: String,
+		body: &[u8],
+	) -> Result<T, Error> {
+		serde_json::from_slice(body).with_context(move || Deserialization {
+			endpoint,
+			url,
+			string: String::from_utf8_lossy(body).into_owned(),
+		})
+	}
+
+	/// send a get request to `path` below the base url with `query` and return the raw response
+	pub(crate) async fn get_raw(
+		&self,
+		endpoint: Endpoint,
+		path: &str,
+		query: &(impl Serialize + Sync),
+	) -> Result<HttpResponse, Error> {
+		let url = self.url(path, query).context(Serialization { endpoint })?;
+		self.send(endpoint, HttpRequest::new(Method::Get, url))
+			.await
+	}
+
+	/// send `request` to `endpoint`, retrying failures as allowed by the retry policy
+	///
+	/// every attempt is charged to the quota tracker. Responses with a status outside of 2xx
+	/// are returned as errors.
+	pub(crate) async fn send(
+		&self,
+		endpoint: Endpoint,
+		request: HttpRequest,
+	) -> Result<HttpResponse, Error> {
+		let start = Instant::now();
+		let mut attempt = 1;
+		let mut reauthorized = false;
+		loop {
+			if let Err(exceeded) = self.inner.quota.spend(endpoint) {
+				return Err(Error::QuotaBudget {
+					endpoint,
+					cost: exceeded.cost,
+					remaining: exceeded.remaining,
+				});
+			}
+			let authorized = self
+				.inner
+				.credentials
+				.authorize(request.clone())
+				.await
+				.context(Authentication { endpoint })?;
+			let error = match self.send_once(endpoint, authorized.clone()).await {
+				Ok(response) => return Ok(response),
+				Err(error) => error,
+			};
+			// a token rejected with 401 may have been revoked or expired early
+			if !reauthorized
+				&& error.is_unauthorized()
+				&& self
+					.inner
+					.credentials
+					.reauthorize(&authorized)
+					.await
+					.context(Authentication { endpoint })?
+			{
+				reauthorized = true;
+				continue;
+			}
+			let delay = self
+				.inner
+				.retry_policy
+				.retry_after(
+					&error,
+					attempt,
+					start.elapsed(),
+					request.method.is_idempotent(),
+				)
+				.ok_or(error)?;
+			warn!(
+				"attempt {} of {} failed, retrying in {:?}",
+				attempt, endpoint, delay
+			);
+			Delay::new(delay).await;
+			attempt += 1;
+		}
+	}
+
+	/// send `request` once and turn responses with an error status into errors
+	async fn send_once(
+		&self,
+		endpoint: Endpoint,
+		request: HttpRequest,
+	) -> Result<HttpResponse, Error> {
+		let redacted = redact(&request.url);
+		debug!("{} {}", request.method, redacted);
+		let response = self
+			.inner
+			.transport
+			.send(request)
+			.await
+			.context(Connection {
+				endpoint,
+				url: redacted.clone(),
+			})?;
+		if !(200..300).contains(&response.status) {
+			let body = String::from_utf8_lossy(&response.body).into_owned();
+			return Err(match ApiError::from_body(&body) {
+				Some(source) if source.is_auth_error() => Error::Auth {
+					endpoint,
+					url: redacted,
+					source,
+				},
+				Some(source) => Error::Api {
+					endpoint,
+					url: redacted,
+					source,
+				},
+				None => Error::Http {
+					endpoint,
+					url: redacted,
+					status: response.status,
+					body,
+				},
+			});
+		}
+		Ok(response)
+	}
+
+	/// build the full url for the endpoint `path` with the system parameters and `query`
+	fn url(
+		&self,
+		path: &str,
+		query: &impl Serialize,
+	) -> Result<String, serde_urlencoded::ser::Error> {
+		let parameters = serde_urlencoded::to_string(&self.inner.parameters)?;
+		let query = serde_urlencoded::to_string(query)?;
+		if query.is_empty() {
+			Ok(format!("{}{}?{}", self.inner.base_url, path, parameters))
+		} else {
+			Ok(format!(
+				"{}{}?{}&{}",
+				self.inner.base_url, path, parameters, query
+			))
+		}
+	}
+}
+
+impl fmt::Debug for Client {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Client")
+			.field("base_url", &self.inner.base_url)
+			.field("pretty_print", &self.inner.parameters.pretty_print)
+			.field("quota_user", &self.inner.parameters.quota_user)
+			.field("hl", &self.inner.hl)
+			.field("region_code", &self.inner.region_code)
+			.field("retry_policy", &self.inner.retry_policy)
+			.field("quota", &self.inner.quota)
+			.finish()
+	}
+}
+
+/// builder for a [`Client`](./struct.Client.html)
+#[derive(Clone)]
+pub struct ClientBuilder {
+	credentials: Credentials,
+	root_url: String,
+	service_path: String,
+	hl: Option<String>,
+	region_code: Option<String>,
+	pretty_print: bool,
+	quota_user: Option<String>,
+	retry_policy: RetryPolicy,
+	quota: Option<Arc<QuotaTracker>>,
+	transport: Option<Arc<dyn HttpTransport>>,
+}
+
+impl fmt::Debug for ClientBuilder {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ClientBuilder")
+			.field("root_url", &self.root_url)
+			.field("service_path", &self.service_path)
+			.field("hl", &self.hl)
+			.field("region_code", &self.region_code)
+			.field("pretty_print", &self.pretty_print)
+			.field("quota_user", &self.quota_user)
+			.field("retry_policy", &self.retry_policy)
+			.field("quota", &self.quota)
+			.finish()
+	}
+}
+
+impl ClientBuilder {
+	/// root url of the api, defaults to `https://www.googleapis.com/`
+	///
+	/// this can point to a local server like `http://127.0.0.1:8080` to run requests against a
+	/// stand-in of the api.
+	#[must_use]
+	pub fn root_url(mut self, root_url: impl Into<String>) -> Self {
+		self.root_url = root_url.into();
+		self
+	}
+
+	/// path of the youtube service below the root url, defaults to `youtube/v3/`
+	#[must_use]
+	pub fn service_path(mut self, service_path: impl Into<String>) -> Self {
+		self.service_path = service_path.into();
+		self
+	}
+
+	/// default language used by requests supporting the `hl` parameter
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		self.hl = Some(hl.into());
+		self
+	}
+
+	/// default region used by requests supporting the `regionCode` parameter
+	#[must_use]
+	pub fn region_code(mut self, region_code: impl Into<String>) -> Self {
+		self.region_code = Some(region_code.into());
+		self
+	}
+
+	/// whether the api should indent its responses, defaults to `false`
+	#[must_use]
+	pub fn pretty_print(mut self, pretty_print: bool) -> Self {
+		self.pretty_print = pretty_print;
+		self
+	}
+
+	/// identifier of the end user the quota should be attributed to
+	#[must_use]
+	pub fn quota_user(mut self, quota_user: impl Into<String>) -> Self {
+		self.quota_user = Some(quota_user.into());
+		self
+	}
+
+	/// policy used to retry failed requests, can be overridden per request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.retry_policy = retry_policy;
+		self
+	}
+
+	/// tracker accounting for the quota units spent, which can be shared between clients
+	#[must_use]
+	pub fn quota_tracker(mut self, quota: impl Into<Arc<QuotaTracker>>) -> Self {
+		self.quota = Some(quota.into());
+		self
+	}
+
+	/// transport sending the requests, defaults to the first one enabled by the cargo features
+	#[must_use]
+	pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+		self.transport = Some(Arc::new(transport));
+		self
+	}
+
+	/// # Panics
+	///
+	/// panics if no transport was set and none is enabled by the cargo features
+	#[must_use]
+	pub fn build(self) -> Client {
+		let transport = self
+			.transport
+			.or_else(default_transport)
+			.expect("no http transport enabled, set one with `ClientBuilder::transport`");
+
+		let root_url = self.root_url.trim_end_matches('/');
+		let service_path = self.service_path.trim_matches('/');
+		let base_url = if service_path.is_empty() {
+			format!("{}/", root_url)
+		} else {
+			format!("{}/{}/", root_url, service_path)
+		};
+
+		Client {
+			inner: Arc::new(Inner {
+				base_url,
+				parameters: SystemParameters {
+					key: self.credentials.api_key().cloned(),
+					pretty_print: self.pretty_print,
+					quota_user: self.quota_user,
+				},
+				hl: self.hl,
+				region_code: self.region_code,
+				retry_policy: self.retry_policy,
+				quota: self.quota.unwrap_or_default(),
+				transport,
+				credentials: self.credentials,
+				reference: Arc::default(),
+			}),
+		}
+	}
+}
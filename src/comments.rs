@@ -0,0 +1,344 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::PageInfo,
+	error::Validation,
+	fields::{Schema, LEAF, PAGE_INFO},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the comments endpoint
+///
+/// exactly one of [`id`](#method.id) and [`parent_id`](#method.parent_id) has to be set.
+pub struct CommentsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<CommentsListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<CommentPart>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	parent_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	text_format: Option<TextFormat>,
+}
+
+impl CommentsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(CommentsListData {
+				fields: None,
+				part: vec![CommentPart::Snippet],
+				id: Vec::new(),
+				parent_id: None,
+				max_results: None,
+				page_token: None,
+				text_format: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = CommentPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the comments to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the replies to a top level comment
+	#[must_use]
+	pub fn parent_id(mut self, parent_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.parent_id = Some(parent_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// format of `textDisplay`, defaults to html
+	#[must_use]
+	pub fn text_format(mut self, text_format: impl Into<TextFormat>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.text_format = Some(text_format.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<Comment, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for CommentsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for CommentsListData {
+	const ENDPOINT: Endpoint = Endpoint::CommentsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		ensure!(
+			self.id.is_empty() != self.parent_id.is_none(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of id and parentId must be set",
+			}
+		);
+		ensure!(
+			self.max_results
+				.is_none_or(|max_results| (1..=100).contains(&max_results)),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 1 and 100",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &COMMENT_SCHEMA),
+]);
+
+pub(crate) static COMMENT_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("authorDisplayName", &LEAF),
+			("authorProfileImageUrl", &LEAF),
+			("authorChannelUrl", &LEAF),
+			("authorChannelId", &Schema(&[("value", &LEAF)])),
+			("channelId", &LEAF),
+			("videoId", &LEAF),
+			("textDisplay", &LEAF),
+			("textOriginal", &LEAF),
+			("parentId", &LEAF),
+			("canRate", &LEAF),
+			("viewerRating", &LEAF),
+			("likeCount", &LEAF),
+			("moderationStatus", &LEAF),
+			("publishedAt", &LEAF),
+			("updatedAt", &LEAF),
+		]),
+	),
+]);
+
+/// resource part which can be requested with [`part`](./struct.CommentsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentPart {
+	Id,
+	Snippet,
+}
+
+/// format of the `textDisplay` of comments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextFormat {
+	Html,
+	PlainText,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<Comment>,
+}
+
+/// comment resource, the snippet is only set when it was requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Comment {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub author_display_name: Option<String>,
+	pub author_profile_image_url: Option<String>,
+	pub author_channel_url: Option<String>,
+	pub author_channel_id: Option<AuthorChannelId>,
+	pub channel_id: Option<String>,
+	pub video_id: Option<String>,
+	/// text in the requested [`TextFormat`](./enum.TextFormat.html)
+	pub text_display: Option<String>,
+	/// text as written by the author, only returned to the author
+	pub text_original: Option<String>,
+	/// id of the top level comment, only set for replies
+	pub parent_id: Option<String>,
+	pub can_rate: Option<bool>,
+	pub viewer_rating: Option<String>,
+	pub like_count: Option<u64>,
+	pub moderation_status: Option<String>,
+	pub published_at: Option<DateTime<Utc>>,
+	pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AuthorChannelId {
+	pub value: String,
+}
+
+impl Page for Response {
+	type Item = Comment;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<Comment> {
+		self.items
+	}
+}
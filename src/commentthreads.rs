@@ -0,0 +1,624 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use futures::{
+	future::BoxFuture,
+	stream::{BoxStream, StreamExt, TryStreamExt},
+};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+pub use crate::comments::TextFormat;
+
+use crate::{
+	comments::{Comment, CommentsList, COMMENT_SCHEMA},
+	common::PageInfo,
+	error::Validation,
+	fields::{Schema, LEAF, PAGE_INFO},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the commentThreads endpoint
+///
+/// exactly one of [`all_threads_related_to_channel_id`](#method.all_threads_related_to_channel_id),
+/// [`id`](#method.id) and [`video_id`](#method.video_id) has to be set.
+pub struct CommentThreadsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<CommentThreadsListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentThreadsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<CommentThreadPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	all_threads_related_to_channel_id: Option<String>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	video_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	moderation_status: Option<ModerationStatus>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	order: Option<Order>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	search_terms: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	text_format: Option<TextFormat>,
+}
+
+impl CommentThreadsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(CommentThreadsListData {
+				fields: None,
+				part: vec![CommentThreadPart::Snippet],
+				all_threads_related_to_channel_id: None,
+				id: Vec::new(),
+				video_id: None,
+				max_results: None,
+				moderation_status: None,
+				order: None,
+				page_token: None,
+				search_terms: None,
+				text_format: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = CommentThreadPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the threads on a channel and on all of its videos
+	#[must_use]
+	pub fn all_threads_related_to_channel_id(
+		mut self,
+		all_threads_related_to_channel_id: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.all_threads_related_to_channel_id = Some(all_threads_related_to_channel_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the threads to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the threads on a video
+	#[must_use]
+	pub fn video_id(mut self, video_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.video_id = Some(video_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// only get threads with this status, requires the owner of the channel or video
+	#[must_use]
+	pub fn moderation_status(mut self, moderation_status: impl Into<ModerationStatus>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.moderation_status = Some(moderation_status.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn order(mut self, order: impl Into<Order>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.order = Some(order.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// only get threads containing these terms
+	#[must_use]
+	pub fn search_terms(mut self, search_terms: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.search_terms = Some(search_terms.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// format of `textDisplay`, defaults to html
+	#[must_use]
+	pub fn text_format(mut self, text_format: impl Into<TextFormat>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.text_format = Some(text_format.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages), [`into_stream`](#method.into_stream) and
+	/// [`trees`](#method.trees) after `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) and [`trees`](#method.trees) after
+	/// `item_limit` threads
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<CommentThread, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+
+	/// stream of every thread with all of its replies
+	///
+	/// the snippet and replies parts are always requested, see
+	/// [`CommentThread::into_tree`](./struct.CommentThread.html#method.into_tree). A field
+	/// mask is extended by the fields needed to find missing replies and further pages.
+	#[must_use]
+	pub fn trees(mut self) -> BoxStream<'static, Result<CommentTree, Error>> {
+		let mut data = self.data.take().unwrap();
+		for part in &[CommentThreadPart::Snippet, CommentThreadPart::Replies] {
+			if !data.part.contains(part) {
+				data.part.push(*part);
+			}
+		}
+		data.fields = data.fields.take().map(|fields| {
+			fields
+				.field("nextPageToken")
+				.field("items/id")
+				.field("items/snippet/topLevelComment/id")
+				.field("items/snippet/totalReplyCount")
+		});
+		let text_format = data.text_format.unwrap_or(TextFormat::Html);
+		let client = self.client.clone();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+			.and_then(move |thread| {
+				let client = client.clone();
+				async move { thread.into_tree(&client, text_format).await }
+			})
+			.boxed()
+	}
+}
+
+impl Future for CommentThreadsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for CommentThreadsListData {
+	const ENDPOINT: Endpoint = Endpoint::CommentThreadsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		let filters = [
+			self.all_threads_related_to_channel_id.is_some(),
+			!self.id.is_empty(),
+			self.video_id.is_some(),
+		];
+		ensure!(
+			filters.iter().filter(|set| **set).count() == 1,
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of allThreadsRelatedToChannelId, id and videoId must be set",
+			}
+		);
+		ensure!(
+			self.max_results
+				.is_none_or(|max_results| (1..=100).contains(&max_results)),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 1 and 100",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &THREAD_SCHEMA),
+]);
+
+static THREAD_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("channelId", &LEAF),
+			("videoId", &LEAF),
+			("topLevelComment", &COMMENT_SCHEMA),
+			("canReply", &LEAF),
+			("totalReplyCount", &LEAF),
+			("isPublic", &LEAF),
+		]),
+	),
+	("replies", &Schema(&[("comments", &COMMENT_SCHEMA)])),
+]);
+
+/// resource part which can be requested with
+/// [`part`](./struct.CommentThreadsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentThreadPart {
+	Id,
+	Replies,
+	Snippet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModerationStatus {
+	HeldForReview,
+	LikelySpam,
+	Published,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Order {
+	Relevance,
+	Time,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<CommentThread>,
+}
+
+/// comment thread, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CommentThread {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub replies: Option<Replies>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub channel_id: Option<String>,
+	/// unset for threads on the channel itself
+	pub video_id: Option<String>,
+	pub top_level_comment: Comment,
+	pub can_reply: Option<bool>,
+	pub total_reply_count: Option<u32>,
+	pub is_public: Option<bool>,
+}
+
+/// replies included in the thread, which are not always all of them
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Replies {
+	pub comments: Vec<Comment>,
+}
+
+/// top level comment of a thread with all of its replies
+#[derive(Debug, Clone, Default)]
+pub struct CommentTree {
+	/// id of the thread
+	pub id: String,
+	pub comment: Comment,
+	pub replies: Vec<Comment>,
+}
+
+impl CommentThread {
+	/// assemble the top level comment and all of its replies
+	///
+	/// the replies included in the thread are used when they are complete, otherwise all
+	/// replies are fetched with [`CommentsList`](../comments/struct.CommentsList.html) in
+	/// `text_format`. The snippet part is required.
+	pub async fn into_tree(
+		self,
+		client: &Client,
+		text_format: TextFormat,
+	) -> Result<CommentTree, Error> {
+		let snippet = match self.snippet {
+			Some(snippet) => snippet,
+			None => {
+				return Validation {
+					endpoint: Endpoint::CommentThreadsList,
+					message: "the snippet part is required to assemble a comment tree",
+				}
+				.fail()
+			}
+		};
+		let comment = snippet.top_level_comment;
+		let included = self
+			.replies
+			.map(|replies| replies.comments)
+			.unwrap_or_default();
+		let total = snippet.total_reply_count.unwrap_or(0) as usize;
+		let replies = if included.len() >= total {
+			included
+		} else {
+			CommentsList::with_client(client.clone())
+				.parent_id(comment.id.clone())
+				.max_results(100)
+				.text_format(text_format)
+				.into_stream()
+				.try_collect()
+				.await?
+		};
+		Ok(CommentTree {
+			id: self.id,
+			comment,
+			replies,
+		})
+	}
+}
+
+impl Page for Response {
+	type Item = CommentThread;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<CommentThread> {
+		self.items
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use futures::{executor::block_on, TryStreamExt};
+
+	use crate::{
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client, FieldMask,
+	};
+
+	const THREADS: &str = r#"{
+		"items": [
+			{
+				"id": "truncated",
+				"snippet": {
+					"topLevelComment": {"id": "top"},
+					"totalReplyCount": 3
+				},
+				"replies": {"comments": [{"id": "first"}]}
+			},
+			{
+				"id": "complete",
+				"snippet": {
+					"topLevelComment": {"id": "other"},
+					"totalReplyCount": 1
+				},
+				"replies": {"comments": [{"id": "inline"}]}
+			}
+		]
+	}"#;
+
+	fn client(transport: &MemoryTransport) -> Client {
+		Client::builder(ApiKey::new("key"))
+			.transport(transport.clone())
+			.build()
+	}
+
+	/// value of the query parameter `name` in `url`
+	fn parameter(url: &str, name: &str) -> Option<String> {
+		let (_, query) = url.split_once('?').unwrap();
+		let query: Vec<(String, String)> = serde_urlencoded::from_str(query).unwrap();
+		query
+			.into_iter()
+			.find(|(key, _)| key == name)
+			.map(|(_, value)| value)
+	}
+
+	#[test]
+	fn fetches_the_replies_missing_from_a_thread() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(200, THREADS));
+		transport.push_response(HttpResponse::new(
+			200,
+			r#"{"nextPageToken":"more","items":[{"id":"first"},{"id":"second"}]}"#,
+		));
+		transport.push_response(HttpResponse::new(200, r#"{"items":[{"id":"third"}]}"#));
+
+		let trees: Vec<_> = block_on(
+			client(&transport)
+				.comment_threads()
+				.video_id("video")
+				.trees()
+				.try_collect(),
+		)
+		.unwrap();
+
+		let replies = |index: usize| -> Vec<&str> {
+			trees[index]
+				.replies
+				.iter()
+				.map(|reply| reply.id.as_str())
+				.collect()
+		};
+		assert_eq!(trees.len(), 2);
+		assert_eq!(
+			(trees[0].id.as_str(), trees[0].comment.id.as_str()),
+			("truncated", "top")
+		);
+		assert_eq!(replies(0), ["first", "second", "third"]);
+		assert_eq!(
+			(trees[1].id.as_str(), trees[1].comment.id.as_str()),
+			("complete", "other")
+		);
+		assert_eq!(replies(1), ["inline"]);
+
+		let requests = transport.requests();
+		assert_eq!(requests.len(), 3);
+		assert!(
+			requests[0].url.contains("/commentThreads?"),
+			"{}",
+			requests[0].url
+		);
+		assert_eq!(
+			parameter(&requests[0].url, "part").as_deref(),
+			Some("snippet,replies")
+		);
+		for request in &requests[1..] {
+			assert!(request.url.contains("/comments?"), "{}", request.url);
+			assert_eq!(parameter(&request.url, "parentId").as_deref(), Some("top"));
+		}
+		assert_eq!(parameter(&requests[1].url, "pageToken"), None);
+		assert_eq!(
+			parameter(&requests[2].url, "pageToken").as_deref(),
+			Some("more")
+		);
+	}
+
+	#[test]
+	fn extends_a_field_mask_by_the_fields_trees_need() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(200, THREADS));
+		transport.push_response(HttpResponse::new(
+			200,
+			r#"{"items":[{"id":"first"},{"id":"second"},{"id":"third"}]}"#,
+		));
+
+		let trees: Vec<_> = block_on(
+			client(&transport)
+				.comment_threads()
+				.video_id("video")
+				.fields(
+					FieldMask::new()
+						.field("items/snippet/topLevelComment/snippet/textDisplay")
+						.field("items/replies"),
+				)
+				.trees()
+				.try_collect(),
+		)
+		.unwrap();
+
+		assert_eq!(trees[0].replies.len(), 3);
+		let requests = transport.requests();
+		assert_eq!(
+			parameter(&requests[0].url, "fields").as_deref(),
+			Some(
+				"items(snippet(topLevelComment(snippet/textDisplay,id),totalReplyCount),replies,id),\
+				 nextPageToken"
+			)
+		);
+	}
+}
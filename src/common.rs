@@ -0,0 +1,42 @@
+//! types shared by the resources of several endpoints
+
+use serde::{Deserialize, Serialize};
+
+use crate::fields::{Schema, LEAF};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PageInfo {
+	pub total_results: i64,
+	pub results_per_page: i64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Thumbnails {
+	pub default: Option<Thumbnail>,
+	pub medium: Option<Thumbnail>,
+	pub high: Option<Thumbnail>,
+	pub standard: Option<Thumbnail>,
+	pub maxres: Option<Thumbnail>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Thumbnail {
+	pub url: String,
+	pub width: Option<u64>,
+	pub height: Option<u64>,
+}
+
+/// title and description of a resource in one language
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Localization {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+}
+
+pub(crate) static LOCALIZATION: Schema = Schema(&[("title", &LEAF), ("description", &LEAF)]);
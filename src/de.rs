@@ -0,0 +1,28 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{de::Error as _, Deserialize, Deserializer};
+
+/// deserialize a number the api encodes as a string, like the statistics counters
+pub(crate) fn number_from_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+	D: Deserializer<'de>,
+	T: FromStr,
+	T::Err: Display,
+{
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum Number {
+		String(String),
+		Number(serde_json::Number),
+	}
+
+	match Option::<Number>::deserialize(deserializer)? {
+		None => Ok(None),
+		Some(Number::String(string)) => string.parse().map(Some).map_err(D::Error::custom),
+		Some(Number::Number(number)) => number
+			.to_string()
+			.parse()
+			.map(Some)
+			.map_err(D::Error::custom),
+	}
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+/// api method a request is sent to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+	SearchList,
+	PlaylistItemsList,
+	PlaylistItemsInsert,
+	PlaylistItemsUpdate,
+	PlaylistItemsDelete,
+	VideosList,
+	ChannelsList,
+	PlaylistsList,
+	PlaylistsInsert,
+	PlaylistsUpdate,
+	PlaylistsDelete,
+	CommentsList,
+	CommentThreadsList,
+	SubscriptionsList,
+	ActivitiesList,
+	VideoCategoriesList,
+	I18nLanguagesList,
+	I18nRegionsList,
+	ChannelSectionsList,
+	CaptionsList,
+	CaptionsDownload,
+	LiveBroadcastsList,
+	LiveStreamsList,
+}
+
+impl Endpoint {
+	/// name of the api method like `search.list`
+	#[must_use]
+	pub fn name(self) -> &'static str {
+		match self {
+			Self::SearchList => "search.list",
+			Self::PlaylistItemsList => "playlistItems.list",
+			Self::PlaylistItemsInsert => "playlistItems.insert",
+			Self::PlaylistItemsUpdate => "playlistItems.update",
+			Self::PlaylistItemsDelete => "playlistItems.delete",
+			Self::VideosList => "videos.list",
+			Self::ChannelsList => "channels.list",
+			Self::PlaylistsList => "playlists.list",
+			Self::PlaylistsInsert => "playlists.insert",
+			Self::PlaylistsUpdate => "playlists.update",
+			Self::PlaylistsDelete => "playlists.delete",
+			Self::CommentsList => "comments.list",
+			Self::CommentThreadsList => "commentThreads.list",
+			Self::SubscriptionsList => "subscriptions.list",
+			Self::ActivitiesList => "activities.list",
+			Self::VideoCategoriesList => "videoCategories.list",
+			Self::I18nLanguagesList => "i18nLanguages.list",
+			Self::I18nRegionsList => "i18nRegions.list",
+			Self::ChannelSectionsList => "channelSections.list",
+			Self::CaptionsList => "captions.list",
+			Self::CaptionsDownload => "captions.download",
+			Self::LiveBroadcastsList => "liveBroadcasts.list",
+			Self::LiveStreamsList => "liveStreams.list",
+		}
+	}
+
+	/// quota units charged for a request to this endpoint
+	#[must_use]
+	pub fn cost(self) -> u32 {
+		match self {
+			Self::SearchList => 100,
+			Self::PlaylistItemsList => 1,
+			Self::PlaylistItemsInsert => 50,
+			Self::PlaylistItemsUpdate => 50,
+			Self::PlaylistItemsDelete => 50,
+			Self::VideosList => 1,
+			Self::ChannelsList => 1,
+			Self::PlaylistsList => 1,
+			Self::PlaylistsInsert => 50,
+			Self::PlaylistsUpdate => 50,
+			Self::PlaylistsDelete => 50,
+			Self::CommentsList => 1,
+			Self::CommentThreadsList => 1,
+			Self::SubscriptionsList => 1,
+			Self::ActivitiesList => 1,
+			Self::VideoCategoriesList => 1,
+			Self::I18nLanguagesList => 1,
+			Self::I18nRegionsList => 1,
+			Self::ChannelSectionsList => 1,
+			Self::CaptionsList => 50,
+			Self::CaptionsDownload => 200,
+			Self::LiveBroadcastsList => 1,
+			Self::LiveStreamsList => 1,
+		}
+	}
+
+	/// path of the resource below the base url of the client
+	#[must_use]
+	pub fn path(self) -> &'static str {
+		match self {
+			Self::SearchList => "search",
+			Self::PlaylistItemsList => "playlistItems",
+			Self::PlaylistItemsInsert => "playlistItems",
+			Self::PlaylistItemsUpdate => "playlistItems",
+			Self::PlaylistItemsDelete => "playlistItems",
+			Self::VideosList => "videos",
+			Self::ChannelsList => "channels",
+			Self::PlaylistsList => "playlists",
+			Self::PlaylistsInsert => "playlists",
+			Self::PlaylistsUpdate => "playlists",
+			Self::PlaylistsDelete => "playlists",
+			Self::CommentsList => "comments",
+			Self::CommentThreadsList => "commentThreads",
+			Self::SubscriptionsList => "subscriptions",
+			Self::ActivitiesList => "activities",
+			Self::VideoCategoriesList => "videoCategories",
+			Self::I18nLanguagesList => "i18nLanguages",
+			Self::I18nRegionsList => "i18nRegions",
+			Self::ChannelSectionsList => "channelSections",
+			Self::CaptionsList => "captions",
+			Self::CaptionsDownload => "captions",
+			Self::LiveBroadcastsList => "liveBroadcasts",
+			Self::LiveStreamsList => "liveStreams",
+		}
+	}
+}
+
+impl fmt::Display for Endpoint {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
@@ -0,0 +1,417 @@
+use std::{error, fmt};
+
+use serde::Deserialize;
+use snafu::Snafu;
+
+use crate::{auth::AuthError, transport::TransportError, Endpoint};
+
+/// error type shared by all endpoints
+///
+/// every variant carries the endpoint of the failed request and, once it could be built, its url
+/// with credentials redacted.
+#[derive(Debug, Snafu)]
+#[snafu(visibility = "pub(crate)")]
+pub enum Error {
+	#[snafu(display("{}: failed to connect to {}: {}", endpoint, url, source))]
+	Connection {
+		endpoint: Endpoint,
+		url: String,
+		source: TransportError,
+	},
+	#[snafu(display("{}: {} returned status {}: {}", endpoint, url, status, body))]
+	Http {
+		endpoint: Endpoint,
+		url: String,
+		status: u16,
+		body: String,
+	},
+	#[snafu(display("{}: {} returned an error: {}", endpoint, url, source))]
+	Api {
+		endpoint: Endpoint,
+		url: String,
+		source: ApiError,
+	},
+	#[snafu(display("{}: {} rejected the credentials: {}", endpoint, url, source))]
+	Auth {
+		endpoint: Endpoint,
+		url: String,
+		source: ApiError,
+	},
+	#[snafu(display("{}: failed to deserialize: {} {}", endpoint, string, source))]
+	Deserialization {
+		endpoint: Endpoint,
+		url: String,
+		string: String,
+		source: serde_json::Error,
+	},
+	#[snafu(display("{}: failed to serialize: {}", endpoint, source))]
+	Serialization {
+		endpoint: Endpoint,
+		source: serde_urlencoded::ser::Error,
+	},
+	#[snafu(display("{}: failed to encode the request body: {}", endpoint, source))]
+	Encoding {
+		endpoint: Endpoint,
+		source: serde_json::Error,
+	},
+	#[snafu(display(
+		"{}: request costing {} units exceeds the remaining quota budget of {} units",
+		endpoint,
+		cost,
+		remaining
+	))]
+	QuotaBudget {
+		endpoint: Endpoint,
+		cost: u32,
+		remaining: u32,
+	},
+	#[snafu(display("{}: invalid request: {}", endpoint, message))]
+	Validation { endpoint: Endpoint, message: String },
+	#[snafu(display("{}: failed to authorize the request: {}", endpoint, source))]
+	Authentication {
+		endpoint: Endpoint,
+		source: AuthError,
+	},
+}
+
+impl Error {
+	/// endpoint of the failed request
+	#[must_use]
+	pub fn endpoint(&self) -> Endpoint {
+		match self {
+			Self::Connection { endpoint, .. }
+			| Self::Http { endpoint, .. }
+			| Self::Api { endpoint, .. }
+			| Self::Auth { endpoint, .. }
+			| Self::Deserialization { endpoint, .. }
+			| Self::Serialization { endpoint, .. }
+			| Self::Encoding { endpoint, .. }
+			| Self::QuotaBudget { endpoint, .. }
+			| Self::Validation { endpoint, .. }
+			| Self::Authentication { endpoint, .. } => *endpoint,
+		}
+	}
+
+	/// url of the failed request with credentials redacted, if it was built
+	#[must_use]
+	pub fn url(&self) -> Option<&str> {
+		match self {
+			Self::Connection { url, .. }
+			| Self::Http { url, .. }
+			| Self::Api { url, .. }
+			| Self::Auth { url, .. }
+			| Self::Deserialization { url, .. } => Some(url),
+			Self::Serialization { .. }
+			| Self::Encoding { .. }
+			| Self::QuotaBudget { .. }
+			| Self::Validation { .. }
+			| Self::Authentication { .. } => None,
+		}
+	}
+
+	/// error envelope returned by the api, if any
+	#[must_use]
+	pub fn api_error(&self) -> Option<&ApiError> {
+		match self {
+			Self::Api { source, .. } | Self::Auth { source, .. } => Some(source),
+			_ => None,
+		}
+	}
+
+	/// whether the daily quota of the project is used up, or the request was not sent as it
+	/// would have exceeded the budget of the client
+	#[must_use]
+	pub fn is_quota_exceeded(&self) -> bool {
+		match self {
+			Self::QuotaBudget { .. } => true,
+			_ => self.api_error().is_some_and(ApiError::is_quota_exceeded),
+		}
+	}
+
+	/// whether the request was rejected because of missing or invalid credentials
+	#[must_use]
+	pub fn is_auth_error(&self) -> bool {
+		match self {
+			Self::Auth { .. } => true,
+			Self::Http { status, .. } => *status == 401,
+			_ => false,
+		}
+	}
+
+	/// whether the credentials were missing, invalid or expired, as opposed to lacking
+	/// permissions
+	pub(crate) fn is_unauthorized(&self) -> bool {
+		match self {
+			Self::Auth { source, .. } => source.code == 401,
+			Self::Http { status, .. } => *status == 401,
+			_ => false,
+		}
+	}
+
+	/// whether the same request might succeed when sent again later
+	#[must_use]
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			Self::Connection { .. } => true,
+			Self::Http { status, .. } => *status == 429 || *status >= 500,
+			Self::Api { source, .. } => source.is_retryable(),
+			_ => false,
+		}
+	}
+}
+
+/// query parameters whose values must never show up in logs or errors
+const SECRET_PARAMETERS: &[&str] = &["key", "access_token"];
+
+/// replace the values of credential query parameters in `url`
+pub(crate) fn redact(url: &str) -> String {
+	let (base, query) = match url.find('?') {
+		Some(index) => url.split_at(index + 1),
+		None => return url.to_string(),
+	};
+	let query = query
+		.split('&')
+		.map(|pair| match pair.find('=') {
+			Some(index) if SECRET_PARAMETERS.contains(&&pair[..index]) => {
+				format!("{}=REDACTED", &pair[..index])
+			}
+			_ => pair.to_string(),
+		})
+		.collect::<Vec<_>>()
+		.join("&");
+	format!("{}{}", base, query)
+}
+
+/// error returned by the api in its json error envelope
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiError {
+	/// http status code of the response
+	pub code: u16,
+	pub message: String,
+	#[serde(default)]
+	pub errors: Vec<ApiErrorItem>,
+	/// canonical status name like `PERMISSION_DENIED`
+	pub status: Option<String>,
+}
+
+/// single entry of the `errors` list of an [`ApiError`](./struct.ApiError.html)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiErrorItem {
+	pub domain: Option<String>,
+	pub reason: Option<String>,
+	pub message: Option<String>,
+	pub location: Option<String>,
+	pub location_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Envelope {
+	error: ApiError,
+}
+
+impl ApiError {
+	const QUOTA_REASONS: &'static [&'static str] = &["quotaExceeded", "dailyLimitExceeded"];
+	const AUTH_REASONS: &'static [&'static str] = &[
+		"authError",
+		"keyInvalid",
+		"keyExpired",
+		"insufficientPermissions",
+	];
+	const RETRYABLE_REASONS: &'static [&'static str] = &[
+		"rateLimitExceeded",
+		"userRateLimitExceeded",
+		"backendError",
+		"internalError",
+	];
+
+	/// decode the error envelope of a response body, if it is one
+	pub(crate) fn from_body(body: &str) -> Option<Self> {
+		serde_json::from_str::<Envelope>(body)
+			.ok()
+			.map(|envelope| envelope.error)
+	}
+
+	/// iterator over the reasons of all error entries
+	pub fn reasons(&self) -> impl Iterator<Item = &str> {
+		self.errors.iter().filter_map(|item| item.reason.as_deref())
+	}
+
+	fn has_reason(&self, reasons: &[&str]) -> bool {
+		self.reasons().any(|reason| reasons.contains(&reason))
+	}
+
+	/// whether the daily quota of the project is used up
+	#[must_use]
+	pub fn is_quota_exceeded(&self) -> bool {
+		self.has_reason(Self::QUOTA_REASONS)
+	}
+
+	/// whether the request was rejected because of missing or invalid credentials
+	#[must_use]
+	pub fn is_auth_error(&self) -> bool {
+		self.code == 401 || self.has_reason(Self::AUTH_REASONS)
+	}
+
+	/// whether the same request might succeed when sent again later
+	#[must_use]
+	pub fn is_retryable(&self) -> bool {
+		self.code == 429 || self.code >= 500 || self.has_reason(Self::RETRYABLE_REASONS)
+	}
+}
+
+impl fmt::Display for ApiError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} {}", self.code, self.message)?;
+		let reasons = self.reasons().collect::<Vec<_>>();
+		if !reasons.is_empty() {
+			write!(f, " ({})", reasons.join(", "))?;
+		}
+		Ok(())
+	}
+}
+
+impl error::Error for ApiError {}
+
+#[cfg(test)]
+mod tests {
+	use futures::executor::block_on;
+
+	use super::{redact, ApiError, Error};
+	use crate::{
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client, Endpoint,
+	};
+
+	const QUOTA_EXCEEDED: &str = r#"{
+		"error": {
+			"code": 403,
+			"message": "The request cannot be completed because you have exceeded your <a href=\"/youtube/v3/getting-started#quota\">quota</a>.",
+			"errors": [
+				{
+					"message": "The request cannot be completed because you have exceeded your <a href=\"/youtube/v3/getting-started#quota\">quota</a>.",
+					"domain": "youtube.quota",
+					"reason": "quotaExceeded"
+				}
+			]
+		}
+	}"#;
+	const RATE_LIMIT_EXCEEDED: &str = r#"{
+		"error": {
+			"code": 403,
+			"message": "The request cannot be completed because you have exceeded your rate limit.",
+			"errors": [
+				{
+					"message": "The request cannot be completed because you have exceeded your rate limit.",
+					"domain": "usageLimits",
+					"reason": "rateLimitExceeded"
+				}
+			],
+			"status": "PERMISSION_DENIED"
+		}
+	}"#;
+	const AUTH_ERROR: &str = r#"{
+		"error": {
+			"code": 401,
+			"message": "Request had invalid authentication credentials. Expected OAuth 2 access token, login cookie or other valid authentication credential.",
+			"errors": [
+				{
+					"message": "Invalid Credentials",
+					"domain": "global",
+					"reason": "authError",
+					"location": "Authorization",
+					"locationType": "header"
+				}
+			],
+			"status": "UNAUTHENTICATED"
+		}
+	}"#;
+
+	fn api(source: ApiError) -> Error {
+		Error::Api {
+			endpoint: Endpoint::SearchList,
+			url: String::from("https://www.googleapis.com/youtube/v3/search?key=REDACTED"),
+			source,
+		}
+	}
+
+	#[test]
+	fn classifies_quota_exceeded() {
+		let error = ApiError::from_body(QUOTA_EXCEEDED).unwrap();
+
+		assert_eq!(error.code, 403);
+		assert_eq!(error.reasons().collect::<Vec<_>>(), ["quotaExceeded"]);
+		assert!(error.is_quota_exceeded());
+		assert!(!error.is_retryable());
+		assert!(!error.is_auth_error());
+		assert!(api(error).is_quota_exceeded());
+	}
+
+	#[test]
+	fn classifies_rate_limit_exceeded() {
+		let error = ApiError::from_body(RATE_LIMIT_EXCEEDED).unwrap();
+
+		assert_eq!(error.status.as_deref(), Some("PERMISSION_DENIED"));
+		assert!(error.is_retryable());
+		assert!(!error.is_quota_exceeded());
+		assert!(!error.is_auth_error());
+		assert!(api(error).is_retryable());
+	}
+
+	#[test]
+	fn classifies_auth_errors() {
+		let error = ApiError::from_body(AUTH_ERROR).unwrap();
+
+		assert_eq!(error.errors[0].location.as_deref(), Some("Authorization"));
+		assert_eq!(error.errors[0].location_type.as_deref(), Some("header"));
+		assert!(error.is_auth_error());
+		assert!(!error.is_retryable());
+		assert!(!error.is_quota_exceeded());
+		assert_eq!(
+			error.to_string(),
+			"401 Request had invalid authentication credentials. Expected OAuth 2 access token, \
+			 login cookie or other valid authentication credential. (authError)"
+		);
+	}
+
+	#[test]
+	fn ignores_bodies_without_an_envelope() {
+		assert!(ApiError::from_body("Service Unavailable").is_none());
+		assert!(ApiError::from_body(r#"{"items":[]}"#).is_none());
+		assert!(ApiError::from_body(r#"{"error":"invalid_grant"}"#).is_none());
+	}
+
+	#[test]
+	fn redacts_credentials_and_keeps_other_parameters() {
+		assert_eq!(
+			redact("https://www.googleapis.com/youtube/v3/search?key=secret&part=snippet&q=key%3Dvalue&access_token=token&pageToken=CAUQAA"),
+			"https://www.googleapis.com/youtube/v3/search?key=REDACTED&part=snippet&q=key%3Dvalue&access_token=REDACTED&pageToken=CAUQAA"
+		);
+		assert_eq!(
+			redact("https://www.googleapis.com/youtube/v3/videos?id=abc&monkey=banana"),
+			"https://www.googleapis.com/youtube/v3/videos?id=abc&monkey=banana"
+		);
+		assert_eq!(
+			redact("https://oauth2.googleapis.com/token"),
+			"https://oauth2.googleapis.com/token"
+		);
+	}
+
+	#[test]
+	fn errors_carry_the_redacted_url() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(403, QUOTA_EXCEEDED));
+		let client = Client::builder(ApiKey::new("secret"))
+			.transport(transport.clone())
+			.build();
+
+		let error = block_on(client.channels().id(["channel"])).unwrap_err();
+
+		assert!(transport.requests()[0].url.contains("key=secret"));
+		let url = error.url().unwrap();
+		assert!(url.contains("key=REDACTED&"), "{}", url);
+		assert!(url.contains("id=channel"), "{}", url);
+		assert!(!error.to_string().contains("secret"), "{}", error);
+		assert!(!format!("{:?}", error).contains("secret"), "{:?}", error);
+	}
+}
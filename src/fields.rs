@@ -0,0 +1,298 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::{error::Validation, Endpoint, Error};
+
+/// selection of response fields sent as the `fields` parameter to receive partial responses
+///
+/// paths are made of field names separated by slashes, like `items/snippet/title`, and are
+/// checked against the fields of the response when the request is sent. A `*` selects all
+/// fields below a path. Fields which were not selected are left at their default values in
+/// the response.
+///
+/// ```rust
+/// # use yt_api::FieldMask;
+/// let fields = FieldMask::new()
+///     .field("items/id")
+///     .field("items/snippet/title")
+///     .field("nextPageToken");
+///
+/// assert_eq!(fields.to_string(), "items(id,snippet/title),nextPageToken");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldMask {
+	paths: Vec<String>,
+}
+
+/// tree of the field names of a resource
+#[derive(Debug)]
+pub(crate) struct Schema(pub(crate) &'static [(&'static str, &'static Schema)]);
+
+/// schema of a field without subfields
+pub(crate) static LEAF: Schema = Schema(&[]);
+
+/// schema of a field whose subfields are not checked, like maps keyed by language
+pub(crate) static ANY: Schema = Schema(&[("*", &ANY)]);
+
+pub(crate) static PAGE_INFO: Schema = Schema(&[("totalResults", &LEAF), ("resultsPerPage", &LEAF)]);
+
+static THUMBNAIL: Schema = Schema(&[("url", &LEAF), ("width", &LEAF), ("height", &LEAF)]);
+
+pub(crate) static THUMBNAILS: Schema = Schema(&[
+	("default", &THUMBNAIL),
+	("medium", &THUMBNAIL),
+	("high", &THUMBNAIL),
+	("standard", &THUMBNAIL),
+	("maxres", &THUMBNAIL),
+]);
+
+impl FieldMask {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// add the field at `path`
+	#[must_use]
+	pub fn field(mut self, path: impl Into<String>) -> Self {
+		self.paths.push(path.into());
+		self
+	}
+
+	/// check that every path names a field of `schema`
+	///
+	/// an empty segment ends the path, as it is dropped from the rendered mask.
+	pub(crate) fn validate(&self, endpoint: Endpoint, schema: &Schema) -> Result<(), Error> {
+		for path in &self.paths {
+			let mut current = schema;
+			for name in path.split('/') {
+				if name.is_empty() || name == "*" {
+					break;
+				}
+				match current
+					.0
+					.iter()
+					.find(|(field, _)| *field == name || *field == "*")
+				{
+					Some((_, subfields)) => current = subfields,
+					None => {
+						return Validation {
+							endpoint,
+							message: format!("unknown field `{}` in field mask", path),
+						}
+						.fail()
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// field name with the selected subfields, in the order they were first added
+///
+/// a field selected as a whole stays a leaf, paths below it would narrow the selection.
+#[derive(Debug)]
+struct Node<'a> {
+	name: &'a str,
+	whole: bool,
+	children: Vec<Node<'a>>,
+}
+
+impl<'a> Node<'a> {
+	fn insert(nodes: &mut Vec<Node<'a>>, names: &[&'a str]) {
+		let name = match names.first() {
+			Some(name) if !name.is_empty() => *name,
+			_ => return,
+		};
+		let index = match nodes.iter().position(|node| node.name == name) {
+			Some(index) => index,
+			None => {
+				nodes.push(Node {
+					name,
+					whole: false,
+					children: Vec::new(),
+				});
+				nodes.len() - 1
+			}
+		};
+		let node = &mut nodes[index];
+		let rest = &names[1..];
+		if rest.first().is_none_or(|name| name.is_empty()) {
+			node.whole = true;
+			node.children.clear();
+		} else if !node.whole {
+			Self::insert(&mut node.children, rest);
+		}
+	}
+
+	fn render(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.name)?;
+		match self.children.as_slice() {
+			[] => Ok(()),
+			[child] => {
+				f.write_str("/")?;
+				child.render(f)
+			}
+			children => {
+				f.write_str("(")?;
+				Self::render_all(children, f)?;
+				f.write_str(")")
+			}
+		}
+	}
+
+	fn render_all(nodes: &[Node<'_>], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (index, node) in nodes.iter().enumerate() {
+			if index > 0 {
+				f.write_str(",")?;
+			}
+			node.render(f)?;
+		}
+		Ok(())
+	}
+}
+
+impl fmt::Display for FieldMask {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut nodes = Vec::new();
+		for path in &self.paths {
+			Node::insert(&mut nodes, &path.split('/').collect::<Vec<_>>());
+		}
+		Node::render_all(&nodes, f)
+	}
+}
+
+impl Serialize for FieldMask {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use futures::executor::block_on;
+
+	use super::{FieldMask, Schema, LEAF};
+	use crate::{
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client, Endpoint,
+	};
+
+	static SCHEMA: Schema = Schema(&[
+		("nextPageToken", &LEAF),
+		(
+			"items",
+			&Schema(&[("id", &LEAF), ("snippet", &Schema(&[("title", &LEAF)]))]),
+		),
+	]);
+
+	#[test]
+	fn nests_paths_with_a_shared_prefix() {
+		let fields = FieldMask::new()
+			.field("items/id")
+			.field("items/snippet/title")
+			.field("items/snippet/description")
+			.field("nextPageToken");
+
+		assert_eq!(
+			fields.to_string(),
+			"items(id,snippet(title,description)),nextPageToken"
+		);
+	}
+
+	#[test]
+	fn keeps_the_order_fields_were_first_added() {
+		let fields = FieldMask::new()
+			.field("nextPageToken")
+			.field("items/snippet")
+			.field("items/id")
+			.field("nextPageToken");
+
+		assert_eq!(fields.to_string(), "nextPageToken,items(snippet,id)");
+	}
+
+	#[test]
+	fn field_selected_as_a_whole_is_not_narrowed() {
+		let parent_first = FieldMask::new()
+			.field("items/snippet")
+			.field("items/snippet/title");
+		let child_first = FieldMask::new()
+			.field("items/snippet/title")
+			.field("items/snippet/description")
+			.field("items/snippet");
+
+		assert_eq!(parent_first.to_string(), "items/snippet");
+		assert_eq!(child_first.to_string(), "items/snippet");
+	}
+
+	#[test]
+	fn renders_wildcards() {
+		let fields = FieldMask::new()
+			.field("items/snippet/*")
+			.field("items/id")
+			.field("*");
+
+		assert_eq!(fields.to_string(), "items(snippet/*,id),*");
+	}
+
+	#[test]
+	fn ignores_empty_segments() {
+		let fields = FieldMask::new().field("").field("items/").field("items/id");
+
+		assert_eq!(fields.to_string(), "items");
+	}
+
+	#[test]
+	fn validates_paths_against_the_schema() {
+		let validate = |path: &str| {
+			FieldMask::new()
+				.field(path)
+				.validate(Endpoint::ChannelsList, &SCHEMA)
+		};
+
+		assert!(validate("items/snippet/title").is_ok());
+		assert!(validate("items/*").is_ok());
+		assert!(validate("items/snippet/description").is_err());
+		assert!(validate("item").is_err());
+	}
+
+	#[test]
+	fn validates_empty_segments_like_they_are_rendered() {
+		let fields = FieldMask::new().field("").field("items/").field("items/id");
+
+		assert!(fields.validate(Endpoint::ChannelsList, &SCHEMA).is_ok());
+		assert!(FieldMask::new()
+			.field("items//unknown")
+			.validate(Endpoint::ChannelsList, &SCHEMA)
+			.is_ok());
+		assert!(FieldMask::new()
+			.field("unknown/")
+			.validate(Endpoint::ChannelsList, &SCHEMA)
+			.is_err());
+	}
+
+	#[test]
+	fn sends_a_mask_with_empty_segments() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+		let client = Client::builder(ApiKey::new("key"))
+			.transport(transport.clone())
+			.build();
+
+		block_on(
+			client
+				.channels()
+				.id(["channel"])
+				.fields(FieldMask::new().field("").field("items/").field("items/id")),
+		)
+		.unwrap();
+
+		let url = &transport.requests()[0].url;
+		assert!(url.contains("&fields=items&"), "{}", url);
+	}
+}
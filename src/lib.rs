@@ -17,11 +17,61 @@
 //! # });
 //! ```
 //!
+//! ## Sharing a client
+//!
+//! A [`Client`][client] holds the api key, a connection pool and default parameters.
+//! It is cheap to clone and can create requests for every endpoint.
+//!
+//! ```rust
+//! # use yt_api::{ApiKey, Client};
+//! #
+//! # futures::executor::block_on(async {
+//! let client = Client::builder(ApiKey::new("your-youtube-api-key"))
+//!     .region_code("DE")
+//!     .quota_user("some-user")
+//!     .build();
+//! let result = client.search().q("rust lang").await;
+//! # });
+//! ```
+//!
 //! [search_list]: ./search/struct.SearchList.html
 //! [search_perform]: ./search/struct.SearchList.html#method.perform
+//! [client]: ./struct.Client.html
 
+pub mod auth;
+mod client;
+pub mod common;
+mod de;
+mod endpoint;
+mod error;
+mod fields;
+mod pagination;
+mod params;
+mod quota;
+mod retry;
 pub mod search;
+pub mod transport;
 pub mod playlistitems;
+pub mod videos;
+pub mod channels;
+pub mod playlists;
+pub mod comments;
+pub mod commentthreads;
+pub mod subscriptions;
+pub mod activities;
+pub mod reference;
+pub mod channelsections;
+pub mod captions;
+pub mod livebroadcasts;
+pub mod livestreams;
+
+pub use auth::Credentials;
+pub use client::{Client, ClientBuilder};
+pub use endpoint::Endpoint;
+pub use error::{ApiError, ApiErrorItem, Error};
+pub use fields::FieldMask;
+pub use quota::QuotaTracker;
+pub use retry::RetryPolicy;
 
 use serde::Serialize;
 
@@ -0,0 +1,519 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::{PageInfo, Thumbnails},
+	de,
+	error::Validation,
+	fields::{Schema, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the liveBroadcasts endpoint
+///
+/// exactly one of [`broadcast_status`](#method.broadcast_status), [`id`](#method.id) and
+/// [`mine`](#method.mine) has to be set.
+pub struct LiveBroadcastsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<LiveBroadcastsListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveBroadcastsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<LiveBroadcastPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	broadcast_status: Option<BroadcastStatus>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	mine: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	broadcast_type: Option<BroadcastType>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner_channel: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+}
+
+impl LiveBroadcastsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(LiveBroadcastsListData {
+				fields: None,
+				part: vec![LiveBroadcastPart::Snippet],
+				broadcast_status: None,
+				id: Vec::new(),
+				mine: None,
+				broadcast_type: None,
+				max_results: None,
+				on_behalf_of_content_owner: None,
+				on_behalf_of_content_owner_channel: None,
+				page_token: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = LiveBroadcastPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the broadcasts of the authorized user in this state
+	#[must_use]
+	pub fn broadcast_status(mut self, broadcast_status: impl Into<BroadcastStatus>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.broadcast_status = Some(broadcast_status.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the broadcasts to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the broadcasts of the authorized user
+	#[must_use]
+	pub fn mine(mut self, mine: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.mine = Some(mine);
+		self.data = Some(data);
+		self
+	}
+
+	/// only get broadcasts of this type, defaults to events
+	#[must_use]
+	pub fn broadcast_type(mut self, broadcast_type: impl Into<BroadcastType>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.broadcast_type = Some(broadcast_type.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner_channel(
+		mut self,
+		on_behalf_of_content_owner_channel: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<LiveBroadcast, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for LiveBroadcastsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for LiveBroadcastsListData {
+	const ENDPOINT: Endpoint = Endpoint::LiveBroadcastsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		let filters = [
+			self.broadcast_status.is_some(),
+			!self.id.is_empty(),
+			self.mine.is_some(),
+		];
+		ensure!(
+			filters.iter().filter(|set| **set).count() == 1,
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of broadcastStatus, id and mine must be set",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &BROADCAST_SCHEMA),
+]);
+
+static BROADCAST_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelId", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			("thumbnails", &THUMBNAILS),
+			("scheduledStartTime", &LEAF),
+			("scheduledEndTime", &LEAF),
+			("actualStartTime", &LEAF),
+			("actualEndTime", &LEAF),
+			("isDefaultBroadcast", &LEAF),
+			("liveChatId", &LEAF),
+		]),
+	),
+	(
+		"status",
+		&Schema(&[
+			("lifeCycleStatus", &LEAF),
+			("privacyStatus", &LEAF),
+			("recordingStatus", &LEAF),
+			("madeForKids", &LEAF),
+			("selfDeclaredMadeForKids", &LEAF),
+		]),
+	),
+	(
+		"contentDetails",
+		&Schema(&[
+			("boundStreamId", &LEAF),
+			("boundStreamLastUpdateTimeMs", &LEAF),
+			(
+				"monitorStream",
+				&Schema(&[
+					("enableMonitorStream", &LEAF),
+					("broadcastStreamDelayMs", &LEAF),
+					("embedHtml", &LEAF),
+				]),
+			),
+			("enableEmbed", &LEAF),
+			("enableDvr", &LEAF),
+			("recordFromStart", &LEAF),
+			("enableClosedCaptions", &LEAF),
+			("closedCaptionsType", &LEAF),
+			("projection", &LEAF),
+			("enableLowLatency", &LEAF),
+			("latencyPreference", &LEAF),
+			("enableAutoStart", &LEAF),
+			("enableAutoStop", &LEAF),
+		]),
+	),
+	("statistics", &Schema(&[("concurrentViewers", &LEAF)])),
+	(
+		"monetizationDetails",
+		&Schema(&[(
+			"cuepointSchedule",
+			&Schema(&[
+				("enabled", &LEAF),
+				("pauseAdsUntil", &LEAF),
+				("scheduleStrategy", &LEAF),
+				("repeatIntervalSecs", &LEAF),
+			]),
+		)]),
+	),
+]);
+
+/// resource part which can be requested with
+/// [`part`](./struct.LiveBroadcastsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LiveBroadcastPart {
+	ContentDetails,
+	Id,
+	MonetizationDetails,
+	Snippet,
+	Statistics,
+	Status,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BroadcastStatus {
+	Active,
+	All,
+	Completed,
+	Upcoming,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BroadcastType {
+	All,
+	Event,
+	Persistent,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub prev_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<LiveBroadcast>,
+}
+
+/// live broadcast resource, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveBroadcast {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub status: Option<Status>,
+	pub content_details: Option<ContentDetails>,
+	pub statistics: Option<Statistics>,
+	pub monetization_details: Option<MonetizationDetails>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub published_at: Option<DateTime<Utc>>,
+	pub channel_id: Option<String>,
+	pub title: Option<String>,
+	pub description: Option<String>,
+	pub thumbnails: Option<Thumbnails>,
+	pub scheduled_start_time: Option<DateTime<Utc>>,
+	pub scheduled_end_time: Option<DateTime<Utc>>,
+	pub actual_start_time: Option<DateTime<Utc>>,
+	pub actual_end_time: Option<DateTime<Utc>>,
+	pub is_default_broadcast: Option<bool>,
+	pub live_chat_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Status {
+	pub life_cycle_status: Option<LifeCycleStatus>,
+	pub privacy_status: Option<String>,
+	/// `notRecording`, `recording` or `recorded`
+	pub recording_status: Option<String>,
+	pub made_for_kids: Option<bool>,
+	pub self_declared_made_for_kids: Option<bool>,
+}
+
+/// state of a broadcast, which moves from `created` over `ready` to `live` and `complete`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LifeCycleStatus {
+	Complete,
+	Created,
+	Live,
+	LiveStarting,
+	Ready,
+	Revoked,
+	TestStarting,
+	Testing,
+	/// status this crate does not know about
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentDetails {
+	/// id of the [`LiveStream`](../livestreams/struct.LiveStream.html) bound to the broadcast
+	pub bound_stream_id: Option<String>,
+	pub bound_stream_last_update_time_ms: Option<DateTime<Utc>>,
+	pub monitor_stream: Option<MonitorStream>,
+	pub enable_embed: Option<bool>,
+	pub enable_dvr: Option<bool>,
+	pub record_from_start: Option<bool>,
+	pub enable_closed_captions: Option<bool>,
+	pub closed_captions_type: Option<String>,
+	pub projection: Option<String>,
+	pub enable_low_latency: Option<bool>,
+	pub latency_preference: Option<String>,
+	pub enable_auto_start: Option<bool>,
+	pub enable_auto_stop: Option<bool>,
+}
+
+/// private preview of the broadcast for its owner
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MonitorStream {
+	pub enable_monitor_stream: Option<bool>,
+	pub broadcast_stream_delay_ms: Option<u32>,
+	pub embed_html: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Statistics {
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub concurrent_viewers: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MonetizationDetails {
+	pub cuepoint_schedule: Option<CuepointSchedule>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CuepointSchedule {
+	pub enabled: Option<bool>,
+	pub pause_ads_until: Option<DateTime<Utc>>,
+	pub schedule_strategy: Option<String>,
+	pub repeat_interval_secs: Option<u32>,
+}
+
+impl Page for Response {
+	type Item = LiveBroadcast;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<LiveBroadcast> {
+		self.items
+	}
+}
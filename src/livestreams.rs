@@ -0,0 +1,472 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::PageInfo,
+	de,
+	error::Validation,
+	fields::{Schema, LEAF, PAGE_INFO},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the liveStreams endpoint
+///
+/// exactly one of [`id`](#method.id) and [`mine`](#method.mine) has to be set.
+pub struct LiveStreamsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<LiveStreamsListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveStreamsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<LiveStreamPart>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	mine: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner_channel: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+}
+
+impl LiveStreamsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(LiveStreamsListData {
+				fields: None,
+				part: vec![LiveStreamPart::Snippet],
+				id: Vec::new(),
+				mine: None,
+				max_results: None,
+				on_behalf_of_content_owner: None,
+				on_behalf_of_content_owner_channel: None,
+				page_token: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = LiveStreamPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the streams to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the streams of the authorized user
+	#[must_use]
+	pub fn mine(mut self, mine: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.mine = Some(mine);
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner_channel(
+		mut self,
+		on_behalf_of_content_owner_channel: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<LiveStream, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for LiveStreamsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for LiveStreamsListData {
+	const ENDPOINT: Endpoint = Endpoint::LiveStreamsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		ensure!(
+			self.id.is_empty() != self.mine.is_none(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of id and mine must be set",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &STREAM_SCHEMA),
+]);
+
+static STREAM_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelId", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			("isDefaultStream", &LEAF),
+		]),
+	),
+	(
+		"cdn",
+		&Schema(&[
+			("ingestionType", &LEAF),
+			(
+				"ingestionInfo",
+				&Schema(&[
+					("streamName", &LEAF),
+					("ingestionAddress", &LEAF),
+					("backupIngestionAddress", &LEAF),
+					("rtmpsIngestionAddress", &LEAF),
+					("rtmpsBackupIngestionAddress", &LEAF),
+				]),
+			),
+			("resolution", &LEAF),
+			("frameRate", &LEAF),
+		]),
+	),
+	(
+		"status",
+		&Schema(&[
+			("streamStatus", &LEAF),
+			(
+				"healthStatus",
+				&Schema(&[
+					("status", &LEAF),
+					("lastUpdateTimeSeconds", &LEAF),
+					(
+						"configurationIssues",
+						&Schema(&[
+							("type", &LEAF),
+							("severity", &LEAF),
+							("reason", &LEAF),
+							("description", &LEAF),
+						]),
+					),
+				]),
+			),
+		]),
+	),
+	(
+		"contentDetails",
+		&Schema(&[("closedCaptionsIngestionUrl", &LEAF), ("isReusable", &LEAF)]),
+	),
+]);
+
+/// resource part which can be requested with [`part`](./struct.LiveStreamsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LiveStreamPart {
+	Cdn,
+	ContentDetails,
+	Id,
+	Snippet,
+	Status,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub prev_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<LiveStream>,
+}
+
+/// live stream resource carrying the video of one or more broadcasts, its parts are only set
+/// when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveStream {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub cdn: Option<Cdn>,
+	pub status: Option<Status>,
+	pub content_details: Option<ContentDetails>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub published_at: Option<DateTime<Utc>>,
+	pub channel_id: Option<String>,
+	pub title: Option<String>,
+	pub description: Option<String>,
+	pub is_default_stream: Option<bool>,
+}
+
+/// where and how the video is sent to youtube
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Cdn {
+	/// `rtmp`, `dash`, `webrtc` or `hls`
+	pub ingestion_type: Option<String>,
+	pub ingestion_info: Option<IngestionInfo>,
+	pub resolution: Option<String>,
+	pub frame_rate: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IngestionInfo {
+	/// stream key, which has to be kept secret
+	pub stream_name: Option<String>,
+	pub ingestion_address: Option<String>,
+	pub backup_ingestion_address: Option<String>,
+	pub rtmps_ingestion_address: Option<String>,
+	pub rtmps_backup_ingestion_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Status {
+	pub stream_status: Option<StreamStatus>,
+	pub health_status: Option<HealthStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StreamStatus {
+	Active,
+	Created,
+	Error,
+	Inactive,
+	Ready,
+	/// status this crate does not know about
+	#[serde(other)]
+	Other,
+}
+
+/// health of the incoming video
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HealthStatus {
+	pub status: Option<Health>,
+	/// unix timestamp of the last update of the status
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub last_update_time_seconds: Option<u64>,
+	pub configuration_issues: Vec<ConfigurationIssue>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Health {
+	Bad,
+	Good,
+	NoData,
+	Ok,
+	/// health this crate does not know about
+	#[serde(other)]
+	Other,
+}
+
+/// problem with the settings of the encoder
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ConfigurationIssue {
+	/// kind of the issue like `bitrateHigh` or `audioSampleRate`
+	#[serde(rename = "type")]
+	pub issue_type: Option<String>,
+	pub severity: Option<Severity>,
+	pub reason: Option<String>,
+	pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+	Error,
+	Info,
+	Warning,
+	/// severity this crate does not know about
+	#[serde(other)]
+	Other,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentDetails {
+	pub closed_captions_ingestion_url: Option<String>,
+	/// whether the stream can be bound to more than one broadcast
+	pub is_reusable: Option<bool>,
+}
+
+impl Page for Response {
+	type Item = LiveStream;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<LiveStream> {
+		self.items
+	}
+}
@@ -0,0 +1,229 @@
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{Client, Endpoint, Error};
+
+/// query parameters of a list request
+pub(crate) trait ListRequest: Serialize + Send + Sync + 'static {
+	const ENDPOINT: Endpoint;
+	type Response: Page + DeserializeOwned + Send + 'static;
+
+	/// check the parameters before sending them
+	fn validate(&self) -> Result<(), Error> {
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String);
+}
+
+/// response of a list request which might be continued on a next page
+pub(crate) trait Page {
+	type Item: Send + 'static;
+
+	fn next_page_token(&self) -> Option<&str>;
+
+	fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// send the request described by `data` once
+pub(crate) async fn list<D: ListRequest>(client: Client, data: D) -> Result<D::Response, Error> {
+	data.validate()?;
+	client.get(D::ENDPOINT, &data).await
+}
+
+/// lazily fetch the pages of a list request by following the `nextPageToken`
+///
+/// the stream ends after the last page, the first error or `page_limit` pages.
+pub(crate) fn pages<D: ListRequest>(
+	client: Client,
+	data: D,
+	page_limit: Option<usize>,
+) -> BoxStream<'static, Result<D::Response, Error>> {
+	stream::unfold(Some(data), move |data| {
+		let client = client.clone();
+		async move {
+			let mut data = data?;
+			if let Err(error) = data.validate() {
+				return Some((Err(error), None));
+			}
+			match client.get::<D::Response>(D::ENDPOINT, &data).await {
+				Ok(page) => {
+					let next = match page.next_page_token() {
+						Some(token) if !token.is_empty() => {
+							data.set_page_token(token.to_string());
+							Some(data)
+						}
+						_ => None,
+					};
+					Some((Ok(page), next))
+				}
+				Err(error) => Some((Err(error), None)),
+			}
+		}
+	})
+	.take(page_limit.unwrap_or(usize::MAX))
+	.boxed()
+}
+
+/// lazily fetch the items of all pages of a list request
+///
+/// no further page is requested once `item_limit` items were yielded.
+pub(crate) fn items<D: ListRequest>(
+	client: Client,
+	data: D,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+) -> BoxStream<'static, Result<<D::Response as Page>::Item, Error>> {
+	pages(client, data, page_limit)
+		.map_ok(|page| stream::iter(page.into_items().into_iter().map(Ok)))
+		.try_flatten()
+		.take(item_limit.unwrap_or(usize::MAX))
+		.boxed()
+}
+
+#[cfg(test)]
+mod tests {
+	use futures::{executor::block_on, StreamExt, TryStreamExt};
+
+	use crate::{
+		playlistitems::{PlaylistItems, PlaylistResult},
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client,
+	};
+
+	fn page(ids: &[&str], next_page_token: Option<&str>) -> HttpResponse {
+		let items: Vec<_> = ids
+			.iter()
+			.map(|id| format!(r#"{{"id":"{}"}}"#, id))
+			.collect();
+		let next_page_token = next_page_token
+			.map(|token| format!(r#""nextPageToken":"{}","#, token))
+			.unwrap_or_default();
+		HttpResponse::new(
+			200,
+			format!(r#"{{{}"items":[{}]}}"#, next_page_token, items.join(",")),
+		)
+	}
+
+	fn request(transport: &MemoryTransport) -> PlaylistItems {
+		Client::builder(ApiKey::new("key"))
+			.transport(transport.clone())
+			.build()
+			.playlist_items()
+			.playlist_id("playlist")
+	}
+
+	/// `pageToken` parameters of all requests sent through `transport`
+	fn page_tokens(transport: &MemoryTransport) -> Vec<Option<String>> {
+		transport
+			.requests()
+			.iter()
+			.map(|request| {
+				let (_, query) = request.url.split_once('?').unwrap();
+				let query: Vec<(String, String)> = serde_urlencoded::from_str(query).unwrap();
+				query
+					.into_iter()
+					.find(|(name, _)| name == "pageToken")
+					.map(|(_, value)| value)
+			})
+			.collect()
+	}
+
+	fn ids(items: Vec<PlaylistResult>) -> Vec<String> {
+		items.into_iter().map(|item| item.id).collect()
+	}
+
+	#[test]
+	fn follows_the_next_page_token_until_the_last_page() {
+		let transport = MemoryTransport::new();
+		transport.push_response(page(&["a"], Some("second")));
+		transport.push_response(page(&["b"], Some("third")));
+		transport.push_response(page(&["c"], None));
+		transport.push_response(page(&["unused"], None));
+
+		let pages = request(&transport).pages();
+		assert!(transport.requests().is_empty());
+		let pages: Vec<_> = block_on(pages.try_collect()).unwrap();
+
+		assert_eq!(pages.len(), 3);
+		assert_eq!(
+			page_tokens(&transport),
+			[
+				None,
+				Some(String::from("second")),
+				Some(String::from("third"))
+			]
+		);
+	}
+
+	#[test]
+	fn stops_at_an_empty_page_token() {
+		let transport = MemoryTransport::new();
+		transport.push_response(page(&["a"], Some("")));
+		transport.push_response(page(&["unused"], None));
+
+		let items = block_on(request(&transport).into_stream().try_collect()).unwrap();
+
+		assert_eq!(ids(items), ["a"]);
+		assert_eq!(page_tokens(&transport), [None]);
+	}
+
+	#[test]
+	fn requests_no_more_than_page_limit_pages() {
+		let transport = MemoryTransport::new();
+		transport.push_response(page(&["a", "b"], Some("second")));
+		transport.push_response(page(&["c", "d"], Some("third")));
+		transport.push_response(page(&["unused"], None));
+
+		let items = block_on(
+			request(&transport)
+				.page_limit(2)
+				.into_stream()
+				.try_collect(),
+		)
+		.unwrap();
+
+		assert_eq!(ids(items), ["a", "b", "c", "d"]);
+		assert_eq!(
+			page_tokens(&transport),
+			[None, Some(String::from("second"))]
+		);
+	}
+
+	#[test]
+	fn requests_no_page_after_item_limit_items() {
+		let transport = MemoryTransport::new();
+		transport.push_response(page(&["a", "b"], Some("second")));
+		transport.push_response(page(&["c", "d"], Some("third")));
+		transport.push_response(page(&["unused"], None));
+
+		let items = block_on(
+			request(&transport)
+				.item_limit(3)
+				.into_stream()
+				.try_collect(),
+		)
+		.unwrap();
+
+		assert_eq!(ids(items), ["a", "b", "c"]);
+		assert_eq!(
+			page_tokens(&transport),
+			[None, Some(String::from("second"))]
+		);
+	}
+
+	#[test]
+	fn ends_after_the_first_error() {
+		let transport = MemoryTransport::new();
+		transport.push_response(page(&["a"], Some("second")));
+		transport.push_response(HttpResponse::new(404, "Not Found"));
+		transport.push_response(page(&["unused"], None));
+
+		let results: Vec<_> = block_on(request(&transport).into_stream().collect());
+
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[0].as_ref().unwrap().id, "a");
+		assert!(results[1].is_err());
+		assert_eq!(transport.requests().len(), 2);
+	}
+}
@@ -0,0 +1,34 @@
+use serde::{ser::Error as _, Serialize, Serializer};
+use serde_json::Value;
+
+/// serialize a list of parameter values as a single comma separated value
+///
+/// works for strings as well as unit enum variants like the part enums.
+pub(crate) fn comma_separated<S, T>(items: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: Serialize,
+{
+	let mut values = Vec::with_capacity(items.len());
+	for item in items {
+		match serde_json::to_value(item).map_err(S::Error::custom)? {
+			Value::String(value) => values.push(value),
+			value => values.push(value.to_string()),
+		}
+	}
+	serializer.serialize_str(&values.join(","))
+}
+
+/// percent encode `segment` so it can be used as a single segment of an url path
+pub(crate) fn path_segment(segment: &str) -> String {
+	let mut encoded = String::with_capacity(segment.len());
+	for byte in segment.bytes() {
+		match byte {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+				encoded.push(char::from(byte))
+			}
+			_ => encoded.push_str(&format!("%{:02X}", byte)),
+		}
+	}
+	encoded
+}
@@ -5,40 +5,37 @@ use std::{
 };
 
 use chrono::{DateTime, Utc};
-use futures::future::BoxFuture;
-use log::debug;
+use futures::{future::BoxFuture, stream::BoxStream};
 use serde::{Deserialize, Serialize, Serializer};
-use snafu::{ResultExt, Snafu};
-
-use super::ApiKey;
-
-/// custom error type for the search endpoint
-#[derive(Debug, Snafu)]
-pub enum Error {
-	#[snafu(display("failed to connect to the api: {}", source))]
-	Connection { source: surf::Exception },
-	#[snafu(display("failed to deserialize: {} {}", string, source))]
-	Deserialization {
-		string: String,
-		source: serde_json::Error,
-	},
-	#[snafu(display("failed to serialize: {}", source))]
-	Serialization {
-		source: serde_urlencoded::ser::Error,
-	},
-}
-
-/// request struct for the search endpoint
+use snafu::ensure;
+
+pub use crate::common::{PageInfo, Thumbnail, Thumbnails};
+
+use crate::{
+	error::Validation,
+	fields::{Schema, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params,
+	transport::Method,
+	Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for `playlistItems.list`
 pub struct PlaylistItems {
+	client: Client,
 	future: Option<BoxFuture<'static, Result<Response, Error>>>,
 	data: Option<PlaylistItemsData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct PlaylistItemsData {
-	key: ApiKey,
-	part: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<PlaylistItemPart>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	id: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -54,16 +51,22 @@ struct PlaylistItemsData {
 }
 
 impl PlaylistItems {
-	const URL: &'static str = "https://www.googleapis.com/youtube/v3/playlistItems";
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
 
-	/// create struct with an [`ApiKey`](../struct.ApiKey.html)
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
 	#[must_use]
-	pub fn new(key: ApiKey) -> Self {
+	pub(crate) fn with_client(client: Client) -> Self {
 		Self {
 			future: None,
 			data: Some(PlaylistItemsData {
-				key,
-				part: String::from("snippet"),
+				fields: None,
+				part: vec![PlaylistItemPart::Snippet],
 				id: None,
 				max_results: None,
 				on_behalf_of_content_owner: None,
@@ -71,9 +74,33 @@ impl PlaylistItems {
 				playlist_id: None,
 				video_id: None,
 			}),
+			page_limit: None,
+			item_limit: None,
+			client,
 		}
 	}
 
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = PlaylistItemPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
 	#[must_use]
 	pub fn id(mut self, id: impl Into<String>) -> Self {
 		let mut data = self.data.take().unwrap();
@@ -124,6 +151,42 @@ impl PlaylistItems {
 		self.data = Some(data);
 		self
 	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<PlaylistResult, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
 }
 
 impl Future for PlaylistItems {
@@ -131,24 +194,543 @@ impl Future for PlaylistItems {
 
 	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
 		if self.future.is_none() {
+			let client = self.client.clone();
 			let data = self.data.take().unwrap();
-			self.future = Some(Box::pin(async move {
-				let url = format!(
-					"{}?{}",
-					Self::URL,
-					serde_urlencoded::to_string(&data).context(Serialization)?
-				);
-				debug!("getting {}", url);
-				let response = surf::get(&url).recv_string().await.context(Connection)?;
-				serde_json::from_str(&response)
-					.with_context(move || Deserialization { string: response })
-			}));
+			self.future = Some(Box::pin(pagination::list(client, data)));
 		}
 
 		self.future.as_mut().unwrap().as_mut().poll(cx)
 	}
 }
 
+impl ListRequest for PlaylistItemsData {
+	const ENDPOINT: Endpoint = Endpoint::PlaylistItemsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+/// request struct for `playlistItems.insert`, adding a video to a playlist
+///
+/// needs oauth 2.0 [`Credentials`](../auth/enum.Credentials.html) of the owner of the
+/// playlist.
+pub struct PlaylistItemsInsert {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<PlaylistResult, Error>>>,
+	data: Option<PlaylistItemWrite>,
+}
+
+/// request struct for `playlistItems.update`, changing the position or the details of an item
+///
+/// the api replaces the snippet of the item, so the playlist and the video have to be sent
+/// again. An item without a [`position`](#method.position) is moved to the end of the playlist.
+pub struct PlaylistItemsUpdate {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<PlaylistResult, Error>>>,
+	data: Option<PlaylistItemWrite>,
+}
+
+/// request struct for `playlistItems.delete`, removing an item from its playlist
+pub struct PlaylistItemsDelete {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<(), Error>>>,
+	data: Option<PlaylistItemsDeleteData>,
+}
+
+#[derive(Debug, Clone)]
+struct PlaylistItemWrite {
+	query: WriteQuery,
+	body: PlaylistItemBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WriteQuery {
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<PlaylistItemPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaylistItemBody {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	id: Option<String>,
+	snippet: SnippetBody,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	content_details: Option<ContentDetailsBody>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SnippetBody {
+	playlist_id: String,
+	resource_id: ResourceBody,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	position: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceBody {
+	kind: &'static str,
+	video_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContentDetailsBody {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	note: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	start_at: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	end_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaylistItemsDeleteData {
+	id: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+}
+
+impl PlaylistItemWrite {
+	fn new(id: Option<String>, playlist_id: String, video_id: String) -> Self {
+		Self {
+			query: WriteQuery {
+				part: Vec::new(),
+				on_behalf_of_content_owner: None,
+			},
+			body: PlaylistItemBody {
+				id,
+				snippet: SnippetBody {
+					playlist_id,
+					resource_id: ResourceBody {
+						kind: "youtube#video",
+						video_id,
+					},
+					position: None,
+				},
+				content_details: None,
+			},
+		}
+	}
+
+	fn content_details(&mut self) -> &mut ContentDetailsBody {
+		self.body
+			.content_details
+			.get_or_insert_with(ContentDetailsBody::default)
+	}
+}
+
+impl PlaylistItemsInsert {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html) to add the video
+	/// `video_id` to the playlist `playlist_id`
+	#[must_use]
+	pub fn new(
+		credentials: impl Into<Credentials>,
+		playlist_id: impl Into<String>,
+		video_id: impl Into<String>,
+	) -> Self {
+		Self::with_client(Client::new(credentials), playlist_id, video_id)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(
+		client: Client,
+		playlist_id: impl Into<String>,
+		video_id: impl Into<String>,
+	) -> Self {
+		Self {
+			future: None,
+			data: Some(PlaylistItemWrite::new(
+				None,
+				playlist_id.into(),
+				video_id.into(),
+			)),
+			client,
+		}
+	}
+
+	/// zero based position in the playlist, defaults to the end of the playlist
+	#[must_use]
+	pub fn position(mut self, position: u32) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.position = Some(position);
+		self.data = Some(data);
+		self
+	}
+
+	/// note of the playlist owner about the item, at most 280 characters
+	#[must_use]
+	pub fn note(mut self, note: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.content_details().note = Some(note.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// time the video should start playing at, like `PT1M30S`
+	#[must_use]
+	pub fn start_at(mut self, start_at: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.content_details().start_at = Some(start_at.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// time the video should stop playing at, like `PT3M`
+	#[must_use]
+	pub fn end_at(mut self, end_at: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.content_details().end_at = Some(end_at.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.query.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for PlaylistItemsInsert {
+	type Output = Result<PlaylistResult, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(write(
+				client,
+				Endpoint::PlaylistItemsInsert,
+				Method::Post,
+				data,
+			)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl PlaylistItemsUpdate {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html) to update the item
+	/// `id`, which refers to the video `video_id` in the playlist `playlist_id`
+	#[must_use]
+	pub fn new(
+		credentials: impl Into<Credentials>,
+		id: impl Into<String>,
+		playlist_id: impl Into<String>,
+		video_id: impl Into<String>,
+	) -> Self {
+		Self::with_client(Client::new(credentials), id, playlist_id, video_id)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(
+		client: Client,
+		id: impl Into<String>,
+		playlist_id: impl Into<String>,
+		video_id: impl Into<String>,
+	) -> Self {
+		Self {
+			future: None,
+			data: Some(PlaylistItemWrite::new(
+				Some(id.into()),
+				playlist_id.into(),
+				video_id.into(),
+			)),
+			client,
+		}
+	}
+
+	/// zero based position to move the item to
+	#[must_use]
+	pub fn position(mut self, position: u32) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.position = Some(position);
+		self.data = Some(data);
+		self
+	}
+
+	/// note of the playlist owner about the item, at most 280 characters
+	#[must_use]
+	pub fn note(mut self, note: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.content_details().note = Some(note.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// time the video should start playing at, like `PT1M30S`
+	#[must_use]
+	pub fn start_at(mut self, start_at: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.content_details().start_at = Some(start_at.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// time the video should stop playing at, like `PT3M`
+	#[must_use]
+	pub fn end_at(mut self, end_at: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.content_details().end_at = Some(end_at.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.query.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for PlaylistItemsUpdate {
+	type Output = Result<PlaylistResult, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(write(
+				client,
+				Endpoint::PlaylistItemsUpdate,
+				Method::Put,
+				data,
+			)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+/// send an insert or update of a playlist item, requesting the parts which are written
+async fn write(
+	client: Client,
+	endpoint: Endpoint,
+	method: Method,
+	mut data: PlaylistItemWrite,
+) -> Result<PlaylistResult, Error> {
+	ensure!(
+		data.body.id.as_ref().is_none_or(|id| !id.is_empty()),
+		Validation {
+			endpoint,
+			message: "the id of the playlist item must not be empty",
+		}
+	);
+	ensure!(
+		!data.body.snippet.playlist_id.is_empty(),
+		Validation {
+			endpoint,
+			message: "the playlist id must not be empty",
+		}
+	);
+	ensure!(
+		!data.body.snippet.resource_id.video_id.is_empty(),
+		Validation {
+			endpoint,
+			message: "the video id must not be empty",
+		}
+	);
+	ensure!(
+		data.body
+			.content_details
+			.as_ref()
+			.and_then(|content_details| content_details.note.as_ref())
+			.is_none_or(|note| note.chars().count() <= 280),
+		Validation {
+			endpoint,
+			message: "the note must not be longer than 280 characters",
+		}
+	);
+	data.query.part = vec![PlaylistItemPart::Snippet];
+	if data.body.content_details.is_some() {
+		data.query.part.push(PlaylistItemPart::ContentDetails);
+	}
+	client
+		.send_json(endpoint, method, &data.query, &data.body)
+		.await
+}
+
+impl PlaylistItemsDelete {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html) to delete the item
+	/// `id`
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>, id: impl Into<String>) -> Self {
+		Self::with_client(Client::new(credentials), id)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client, id: impl Into<String>) -> Self {
+		Self {
+			future: None,
+			data: Some(PlaylistItemsDeleteData {
+				id: id.into(),
+				on_behalf_of_content_owner: None,
+			}),
+			client,
+		}
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for PlaylistItemsDelete {
+	type Output = Result<(), Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(delete(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+async fn delete(client: Client, data: PlaylistItemsDeleteData) -> Result<(), Error> {
+	let endpoint = Endpoint::PlaylistItemsDelete;
+	ensure!(
+		!data.id.is_empty(),
+		Validation {
+			endpoint,
+			message: "the id of the playlist item must not be empty",
+		}
+	);
+	client.delete(endpoint, &data).await
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &PLAYLIST_RESULT_SCHEMA),
+]);
+
+static PLAYLIST_RESULT_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelId", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			("thumbnails", &THUMBNAILS),
+			("channelTitle", &LEAF),
+			("videoOwnerChannelTitle", &LEAF),
+			("videoOwnerChannelId", &LEAF),
+			("playlistId", &LEAF),
+			("position", &LEAF),
+			(
+				"resourceId",
+				&Schema(&[("kind", &LEAF), ("videoId", &LEAF)]),
+			),
+		]),
+	),
+	(
+		"contentDetails",
+		&Schema(&[
+			("videoId", &LEAF),
+			("startAt", &LEAF),
+			("endAt", &LEAF),
+			("note", &LEAF),
+			("videoPublishedAt", &LEAF),
+		]),
+	),
+	("status", &Schema(&[("privacyStatus", &LEAF)])),
+]);
+
+/// resource part which can be requested with [`part`](./struct.PlaylistItems.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlaylistItemPart {
+	Id,
+	Snippet,
+	ContentDetails,
+	Status,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ChannelType {
@@ -259,8 +841,8 @@ pub enum VideoType {
 	Movie,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Response {
 	pub kind: String,
 	pub etag: String,
@@ -270,25 +852,20 @@ pub struct Response {
 	pub items: Vec<PlaylistResult>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PageInfo {
-	pub total_results: i64,
-	pub results_per_page: i64,
-}
-
-#[derive(Debug, Clone, Deserialize)]
+/// playlist item, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct PlaylistResult {
 	pub kind: String,
 	pub etag: String,
 	pub id: String,
-	pub snippet: Snippet,
+	pub snippet: Option<Snippet>,
 	pub content_details: Option<ContentDetails>,
 	pub status: Option<Status>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Snippet {
 	pub published_at: Option<DateTime<Utc>>,
 	pub channel_id: Option<String>,
@@ -303,40 +880,37 @@ pub struct Snippet {
 	pub resource_id: Resource,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct Thumbnails {
-	default: Option<Thumbnail>,
-	medium: Option<Thumbnail>,
-	high: Option<Thumbnail>,
-	standard: Option<Thumbnail>,
-	maxres: Option<Thumbnail>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-pub struct Thumbnail {
-	pub url: String,
-	pub width: Option<u64>,
-	pub height: Option<u64>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Resource {
 	pub kind: String,
 	pub video_id: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ContentDetails {
 	pub video_id: String,
-	pub start_at: String,
-	pub end_at: String,
-	pub note: String,
+	pub start_at: Option<String>,
+	pub end_at: Option<String>,
+	pub note: Option<String>,
 	pub video_published_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Status {
 	pub privacy_status: String,
-}
\ No newline at end of file
+}
+
+impl Page for Response {
+	type Item = PlaylistResult;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<PlaylistResult> {
+		self.items
+	}
+}
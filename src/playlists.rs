@@ -0,0 +1,1137 @@
+use std::{
+	collections::HashMap,
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::{Localization, PageInfo, Thumbnails, LOCALIZATION},
+	error::Validation,
+	fields::{Schema, ANY, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params,
+	transport::Method,
+	Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for `playlists.list`
+///
+/// exactly one of [`channel_id`](#method.channel_id), [`id`](#method.id) and
+/// [`mine`](#method.mine) has to be set.
+pub struct PlaylistsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<PlaylistsListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaylistsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<PlaylistPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	channel_id: Option<String>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	mine: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hl: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_height: Option<u16>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_width: Option<u16>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner_channel: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+}
+
+impl PlaylistsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(PlaylistsListData {
+				fields: None,
+				part: vec![PlaylistPart::Snippet],
+				channel_id: None,
+				id: Vec::new(),
+				mine: None,
+				hl: client.hl().cloned(),
+				max_height: None,
+				max_width: None,
+				max_results: None,
+				on_behalf_of_content_owner: None,
+				on_behalf_of_content_owner_channel: None,
+				page_token: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = PlaylistPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the playlists of a channel
+	#[must_use]
+	pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.channel_id = Some(channel_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the playlists to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the playlists of the authorized user
+	#[must_use]
+	pub fn mine(mut self, mine: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.mine = Some(mine);
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.hl = Some(hl.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_height(mut self, max_height: impl Into<u16>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_height = Some(max_height.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_width(mut self, max_width: impl Into<u16>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_width = Some(max_width.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner_channel(
+		mut self,
+		on_behalf_of_content_owner_channel: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<Playlist, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for PlaylistsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for PlaylistsListData {
+	const ENDPOINT: Endpoint = Endpoint::PlaylistsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		let filters = [
+			self.channel_id.is_some(),
+			!self.id.is_empty(),
+			self.mine.is_some(),
+		];
+		ensure!(
+			filters.iter().filter(|set| **set).count() == 1,
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of channelId, id and mine must be set",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+/// request struct for `playlists.insert`, creating a playlist
+///
+/// needs oauth 2.0 [`Credentials`](../auth/enum.Credentials.html) of the channel the playlist
+/// is created for.
+pub struct PlaylistsInsert {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Playlist, Error>>>,
+	data: Option<PlaylistWrite>,
+}
+
+/// request struct for `playlists.update`, changing the metadata of a playlist
+///
+/// the api replaces the written parts, so properties which are not set again are removed
+/// from the playlist, like the description or the tags.
+pub struct PlaylistsUpdate {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Playlist, Error>>>,
+	data: Option<PlaylistWrite>,
+}
+
+/// request struct for `playlists.delete`
+pub struct PlaylistsDelete {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<(), Error>>>,
+	data: Option<PlaylistsDeleteData>,
+}
+
+#[derive(Debug, Clone)]
+struct PlaylistWrite {
+	query: WriteQuery,
+	body: PlaylistBody,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WriteQuery {
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<PlaylistPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner_channel: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaylistBody {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	id: Option<String>,
+	snippet: SnippetBody,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	status: Option<StatusBody>,
+	#[serde(skip_serializing_if = "HashMap::is_empty")]
+	localizations: HashMap<String, Localization>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SnippetBody {
+	title: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	description: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	default_language: Option<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusBody {
+	privacy_status: PrivacyStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaylistsDeleteData {
+	id: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+}
+
+/// visibility of a playlist
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PrivacyStatus {
+	Private,
+	Public,
+	Unlisted,
+}
+
+impl PlaylistWrite {
+	/// longest title the api accepts
+	const MAX_TITLE: usize = 150;
+	/// longest description the api accepts
+	const MAX_DESCRIPTION: usize = 5000;
+	/// longest combined length of all tags the api accepts
+	const MAX_TAGS: usize = 500;
+
+	fn new(id: Option<String>, title: String) -> Self {
+		Self {
+			query: WriteQuery {
+				part: Vec::new(),
+				on_behalf_of_content_owner: None,
+				on_behalf_of_content_owner_channel: None,
+			},
+			body: PlaylistBody {
+				id,
+				snippet: SnippetBody {
+					title,
+					description: None,
+					default_language: None,
+					tags: Vec::new(),
+				},
+				status: None,
+				localizations: HashMap::new(),
+			},
+		}
+	}
+
+	/// check the limits of the api, which would reject the request otherwise
+	fn validate(&self, endpoint: Endpoint) -> Result<(), Error> {
+		let snippet = &self.body.snippet;
+		ensure!(
+			self.body.id.as_ref().is_none_or(|id| !id.is_empty()),
+			Validation {
+				endpoint,
+				message: "the id of the playlist must not be empty",
+			}
+		);
+		validate_title(endpoint, &snippet.title)?;
+		if let Some(description) = &snippet.description {
+			validate_description(endpoint, description)?;
+		}
+		ensure!(
+			snippet
+				.tags
+				.iter()
+				.map(|tag| tag.chars().count())
+				.sum::<usize>()
+				<= Self::MAX_TAGS,
+			Validation {
+				endpoint,
+				message: format!(
+					"the tags must not be longer than {} characters in total",
+					Self::MAX_TAGS
+				),
+			}
+		);
+		ensure!(
+			self.body.localizations.is_empty() || snippet.default_language.is_some(),
+			Validation {
+				endpoint,
+				message: "localizations need a default language",
+			}
+		);
+		for localization in self.body.localizations.values() {
+			if let Some(title) = &localization.title {
+				validate_title(endpoint, title)?;
+			}
+			if let Some(description) = &localization.description {
+				validate_description(endpoint, description)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// parts which are written by the body
+	fn parts(&self) -> Vec<PlaylistPart> {
+		let mut part = vec![PlaylistPart::Snippet];
+		if self.body.status.is_some() {
+			part.push(PlaylistPart::Status);
+		}
+		if !self.body.localizations.is_empty() {
+			part.push(PlaylistPart::Localizations);
+		}
+		part
+	}
+}
+
+fn validate_title(endpoint: Endpoint, title: &str) -> Result<(), Error> {
+	ensure!(
+		!title.trim().is_empty(),
+		Validation {
+			endpoint,
+			message: "the title must not be empty",
+		}
+	);
+	ensure!(
+		title.chars().count() <= PlaylistWrite::MAX_TITLE,
+		Validation {
+			endpoint,
+			message: format!(
+				"the title must not be longer than {} characters",
+				PlaylistWrite::MAX_TITLE
+			),
+		}
+	);
+	ensure!(
+		!title.contains(['<', '>']),
+		Validation {
+			endpoint,
+			message: "the title must not contain < or >",
+		}
+	);
+	Ok(())
+}
+
+fn validate_description(endpoint: Endpoint, description: &str) -> Result<(), Error> {
+	ensure!(
+		description.chars().count() <= PlaylistWrite::MAX_DESCRIPTION,
+		Validation {
+			endpoint,
+			message: format!(
+				"the description must not be longer than {} characters",
+				PlaylistWrite::MAX_DESCRIPTION
+			),
+		}
+	);
+	ensure!(
+		!description.contains(['<', '>']),
+		Validation {
+			endpoint,
+			message: "the description must not contain < or >",
+		}
+	);
+	Ok(())
+}
+
+impl PlaylistsInsert {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html) to create a playlist
+	/// called `title`
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>, title: impl Into<String>) -> Self {
+		Self::with_client(Client::new(credentials), title)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client, title: impl Into<String>) -> Self {
+		Self {
+			future: None,
+			data: Some(PlaylistWrite::new(None, title.into())),
+			client,
+		}
+	}
+
+	/// description of the playlist, at most 5000 characters
+	#[must_use]
+	pub fn description(mut self, description: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.description = Some(description.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// visibility of the playlist, defaults to the setting of the channel
+	#[must_use]
+	pub fn privacy_status(mut self, privacy_status: PrivacyStatus) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.status = Some(StatusBody { privacy_status });
+		self.data = Some(data);
+		self
+	}
+
+	/// language of the title and the description
+	#[must_use]
+	pub fn default_language(mut self, default_language: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.default_language = Some(default_language.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// translation of the title and the description to `language`, needs a
+	/// [`default_language`](#method.default_language)
+	#[must_use]
+	pub fn localization(mut self, language: impl Into<String>, localization: Localization) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body
+			.localizations
+			.insert(language.into(), localization);
+		self.data = Some(data);
+		self
+	}
+
+	/// keywords of the playlist
+	#[must_use]
+	pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.tags = tags.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.query.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// channel the playlist is created for, needs
+	/// [`on_behalf_of_content_owner`](#method.on_behalf_of_content_owner)
+	#[must_use]
+	pub fn on_behalf_of_content_owner_channel(
+		mut self,
+		on_behalf_of_content_owner_channel: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.query.on_behalf_of_content_owner_channel =
+			Some(on_behalf_of_content_owner_channel.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for PlaylistsInsert {
+	type Output = Result<Playlist, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(write(
+				client,
+				Endpoint::PlaylistsInsert,
+				Method::Post,
+				data,
+			)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl PlaylistsUpdate {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html) to update the
+	/// playlist `id`, which is called `title` afterwards
+	#[must_use]
+	pub fn new(
+		credentials: impl Into<Credentials>,
+		id: impl Into<String>,
+		title: impl Into<String>,
+	) -> Self {
+		Self::with_client(Client::new(credentials), id, title)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(
+		client: Client,
+		id: impl Into<String>,
+		title: impl Into<String>,
+	) -> Self {
+		Self {
+			future: None,
+			data: Some(PlaylistWrite::new(Some(id.into()), title.into())),
+			client,
+		}
+	}
+
+	/// description of the playlist, at most 5000 characters
+	#[must_use]
+	pub fn description(mut self, description: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.description = Some(description.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// visibility of the playlist, left unchanged if not set
+	#[must_use]
+	pub fn privacy_status(mut self, privacy_status: PrivacyStatus) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.status = Some(StatusBody { privacy_status });
+		self.data = Some(data);
+		self
+	}
+
+	/// language of the title and the description
+	#[must_use]
+	pub fn default_language(mut self, default_language: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.default_language = Some(default_language.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// translation of the title and the description to `language`, needs a
+	/// [`default_language`](#method.default_language)
+	///
+	/// the translations are left unchanged if none is set.
+	#[must_use]
+	pub fn localization(mut self, language: impl Into<String>, localization: Localization) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body
+			.localizations
+			.insert(language.into(), localization);
+		self.data = Some(data);
+		self
+	}
+
+	/// keywords of the playlist
+	#[must_use]
+	pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.body.snippet.tags = tags.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.query.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for PlaylistsUpdate {
+	type Output = Result<Playlist, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(write(
+				client,
+				Endpoint::PlaylistsUpdate,
+				Method::Put,
+				data,
+			)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+/// send an insert or update of a playlist, requesting the parts which are written
+async fn write(
+	client: Client,
+	endpoint: Endpoint,
+	method: Method,
+	mut data: PlaylistWrite,
+) -> Result<Playlist, Error> {
+	data.validate(endpoint)?;
+	data.query.part = data.parts();
+	client
+		.send_json(endpoint, method, &data.query, &data.body)
+		.await
+}
+
+impl PlaylistsDelete {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html) to delete the
+	/// playlist `id`
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>, id: impl Into<String>) -> Self {
+		Self::with_client(Client::new(credentials), id)
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client, id: impl Into<String>) -> Self {
+		Self {
+			future: None,
+			data: Some(PlaylistsDeleteData {
+				id: id.into(),
+				on_behalf_of_content_owner: None,
+			}),
+			client,
+		}
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for PlaylistsDelete {
+	type Output = Result<(), Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(delete(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+async fn delete(client: Client, data: PlaylistsDeleteData) -> Result<(), Error> {
+	let endpoint = Endpoint::PlaylistsDelete;
+	ensure!(
+		!data.id.is_empty(),
+		Validation {
+			endpoint,
+			message: "the id of the playlist must not be empty",
+		}
+	);
+	client.delete(endpoint, &data).await
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &PLAYLIST_SCHEMA),
+]);
+
+static PLAYLIST_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelId", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			("thumbnails", &THUMBNAILS),
+			("channelTitle", &LEAF),
+			("tags", &LEAF),
+			("defaultLanguage", &LEAF),
+			("localized", &LOCALIZATION),
+		]),
+	),
+	(
+		"status",
+		&Schema(&[("privacyStatus", &LEAF), ("podcastStatus", &LEAF)]),
+	),
+	("contentDetails", &Schema(&[("itemCount", &LEAF)])),
+	(
+		"player",
+		&Schema(&[
+			("embedHtml", &LEAF),
+			("embedHeight", &LEAF),
+			("embedWidth", &LEAF),
+		]),
+	),
+	("localizations", &ANY),
+]);
+
+/// resource part which can be requested with [`part`](./struct.PlaylistsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlaylistPart {
+	ContentDetails,
+	Id,
+	Localizations,
+	Player,
+	Snippet,
+	Status,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub prev_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<Playlist>,
+}
+
+/// playlist resource, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Playlist {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub status: Option<Status>,
+	pub content_details: Option<ContentDetails>,
+	pub player: Option<Player>,
+	/// translations of the title and description keyed by language
+	pub localizations: Option<HashMap<String, Localization>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub published_at: Option<DateTime<Utc>>,
+	pub channel_id: Option<String>,
+	pub title: Option<String>,
+	pub description: Option<String>,
+	pub thumbnails: Option<Thumbnails>,
+	pub channel_title: Option<String>,
+	pub tags: Option<Vec<String>>,
+	pub default_language: Option<String>,
+	/// title and description in the language requested with `hl`
+	pub localized: Option<Localization>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Status {
+	/// `public`, `unlisted` or `private`
+	pub privacy_status: Option<String>,
+	pub podcast_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentDetails {
+	/// number of videos in the playlist
+	pub item_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Player {
+	pub embed_html: Option<String>,
+	pub embed_height: Option<u64>,
+	pub embed_width: Option<u64>,
+}
+
+impl Page for Response {
+	type Item = Playlist;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<Playlist> {
+		self.items
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use futures::executor::block_on;
+
+	use super::{PlaylistsInsert, PrivacyStatus};
+	use crate::{
+		common::Localization,
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client, Endpoint, Error,
+	};
+
+	fn client(transport: &MemoryTransport) -> Client {
+		Client::builder(ApiKey::new("key"))
+			.transport(transport.clone())
+			.build()
+	}
+
+	fn insert(title: &str) -> PlaylistsInsert {
+		client(&MemoryTransport::new()).insert_playlist(title)
+	}
+
+	fn validate(request: PlaylistsInsert) -> Result<(), Error> {
+		request.data.unwrap().validate(Endpoint::PlaylistsInsert)
+	}
+
+	fn part(url: &str) -> String {
+		let (_, query) = url.split_once('?').unwrap();
+		let query: Vec<(String, String)> = serde_urlencoded::from_str(query).unwrap();
+		query
+			.into_iter()
+			.find(|(name, _)| name == "part")
+			.map(|(_, value)| value)
+			.unwrap()
+	}
+
+	fn localization(title: &str, description: &str) -> Localization {
+		Localization {
+			title: Some(String::from(title)),
+			description: Some(String::from(description)),
+		}
+	}
+
+	#[test]
+	fn rejects_empty_titles() {
+		assert!(validate(insert("")).is_err());
+		assert!(validate(insert(" \t\n")).is_err());
+		assert!(validate(insert("a")).is_ok());
+	}
+
+	#[test]
+	fn limits_the_title_to_150_characters() {
+		assert!(validate(insert(&"ä".repeat(150))).is_ok());
+		assert!(validate(insert(&"ä".repeat(151))).is_err());
+	}
+
+	#[test]
+	fn limits_the_description_to_5000_characters() {
+		assert!(validate(insert("title").description("ä".repeat(5000))).is_ok());
+		assert!(validate(insert("title").description("ä".repeat(5001))).is_err());
+	}
+
+	#[test]
+	fn rejects_angle_brackets() {
+		assert!(validate(insert("a < b")).is_err());
+		assert!(validate(insert("a > b")).is_err());
+		assert!(validate(insert("title").description("<b>bold</b>")).is_err());
+		assert!(validate(insert("title").description("a > b")).is_err());
+	}
+
+	#[test]
+	fn limits_the_tags_to_500_characters_in_total() {
+		let tags = |last: usize| vec!["ä".repeat(250), "ä".repeat(last)];
+
+		assert!(validate(insert("title").tags(tags(250))).is_ok());
+		assert!(validate(insert("title").tags(tags(251))).is_err());
+	}
+
+	#[test]
+	fn localizations_need_a_default_language() {
+		let localized = || insert("title").localization("de", localization("Titel", "Text"));
+
+		assert!(validate(localized()).is_err());
+		assert!(validate(localized().default_language("en")).is_ok());
+	}
+
+	#[test]
+	fn validates_localizations_like_the_snippet() {
+		let localized = |title: &str, description: &str| {
+			insert("title")
+				.default_language("en")
+				.localization("de", localization(title, description))
+		};
+
+		assert!(validate(localized(&"ä".repeat(150), &"ä".repeat(5000))).is_ok());
+		assert!(validate(localized(&"ä".repeat(151), "Text")).is_err());
+		assert!(validate(localized("Titel", &"ä".repeat(5001))).is_err());
+		assert!(validate(localized("<Titel>", "Text")).is_err());
+		assert!(validate(localized(" ", "Text")).is_err());
+	}
+
+	#[test]
+	fn rejects_an_empty_update_id() {
+		let update = |id: &str| {
+			client(&MemoryTransport::new())
+				.update_playlist(id, "title")
+				.data
+				.unwrap()
+				.validate(Endpoint::PlaylistsUpdate)
+		};
+
+		assert!(update("").is_err());
+		assert!(update("playlist").is_ok());
+	}
+
+	#[test]
+	fn requests_the_written_parts() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(200, r#"{"id":"created"}"#));
+		transport.push_response(HttpResponse::new(200, r#"{"id":"playlist"}"#));
+		let client = client(&transport);
+
+		let created = block_on(client.insert_playlist("title")).unwrap();
+		let updated = block_on(
+			client
+				.update_playlist("playlist", "title")
+				.privacy_status(PrivacyStatus::Unlisted)
+				.default_language("en")
+				.localization("de", localization("Titel", "Text")),
+		)
+		.unwrap();
+
+		assert_eq!(created.id, "created");
+		assert_eq!(updated.id, "playlist");
+		let parts: Vec<_> = transport
+			.requests()
+			.iter()
+			.map(|request| part(&request.url))
+			.collect();
+		assert_eq!(parts, ["snippet", "snippet,status,localizations"]);
+	}
+
+	#[test]
+	fn sends_no_request_for_an_invalid_body() {
+		let transport = MemoryTransport::new();
+		let client = client(&transport);
+
+		let inserted = block_on(client.insert_playlist("x".repeat(151)));
+		let updated = block_on(client.update_playlist("", "title"));
+		let deleted = block_on(client.delete_playlist(""));
+
+		for result in &[inserted.map(|_| ()), updated.map(|_| ()), deleted] {
+			match result {
+				Err(Error::Validation { .. }) => {}
+				other => panic!("expected a validation error, got {:?}", other),
+			}
+		}
+		assert!(transport.requests().is_empty());
+	}
+}
@@ -0,0 +1,267 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+
+use crate::Endpoint;
+
+/// keeps track of the quota units spent on the current day
+///
+/// the api resets the quota of a project at midnight pacific time, so does the tracker.
+/// Every attempt of a request is counted, including retries and failed requests, as the api
+/// charges those as well.
+#[derive(Debug)]
+pub struct QuotaTracker {
+	daily_limit: u32,
+	enforce: bool,
+	state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+	day: NaiveDate,
+	used: u32,
+}
+
+/// a request was not sent because it would have exceeded the daily quota budget
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BudgetExceeded {
+	pub(crate) cost: u32,
+	pub(crate) remaining: u32,
+}
+
+impl QuotaTracker {
+	/// default daily quota of a project
+	pub const DEFAULT_DAILY_LIMIT: u32 = 10_000;
+
+	/// create a tracker for the default daily limit of 10,000 units which never refuses requests
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			daily_limit: Self::DEFAULT_DAILY_LIMIT,
+			enforce: false,
+			state: Mutex::new(State {
+				day: pacific_day(),
+				used: 0,
+			}),
+		}
+	}
+
+	/// daily quota of the project in units
+	#[must_use]
+	pub fn daily_limit(mut self, daily_limit: u32) -> Self {
+		self.daily_limit = daily_limit;
+		self
+	}
+
+	/// refuse to send requests which would exceed the daily limit
+	#[must_use]
+	pub fn enforce(mut self, enforce: bool) -> Self {
+		self.enforce = enforce;
+		self
+	}
+
+	/// units spent since the last reset
+	#[must_use]
+	pub fn used(&self) -> u32 {
+		self.state().used
+	}
+
+	/// units left until the daily limit is reached
+	#[must_use]
+	pub fn remaining(&self) -> u32 {
+		self.daily_limit.saturating_sub(self.used())
+	}
+
+	/// forget all spent units, e.g. after the quota was raised
+	pub fn reset(&self) {
+		self.state().used = 0;
+	}
+
+	/// account for a request to `endpoint`, failing if an enforced budget would be exceeded
+	pub(crate) fn spend(&self, endpoint: Endpoint) -> Result<(), BudgetExceeded> {
+		let cost = endpoint.cost();
+		let mut state = self.state();
+		let remaining = self.daily_limit.saturating_sub(state.used);
+		if self.enforce && cost > remaining {
+			return Err(BudgetExceeded { cost, remaining });
+		}
+		state.used = state.used.saturating_add(cost);
+		Ok(())
+	}
+
+	/// lock the state, resetting it if a new day started in the meantime
+	fn state(&self) -> std::sync::MutexGuard<'_, State> {
+		let mut state = self
+			.state
+			.lock()
+			.unwrap_or_else(std::sync::PoisonError::into_inner);
+		let today = pacific_day();
+		if state.day != today {
+			state.day = today;
+			state.used = 0;
+		}
+		state
+	}
+}
+
+impl Default for QuotaTracker {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// current date in the pacific time zone
+fn pacific_day() -> NaiveDate {
+	let now = Utc::now();
+	(now + pacific_offset(now)).date_naive()
+}
+
+/// offset of the pacific time zone from utc at `now`
+fn pacific_offset(now: DateTime<Utc>) -> Duration {
+	let year = now.year();
+	// daylight saving time lasts from the second sunday of march at 2am PST
+	// until the first sunday of november at 2am PDT
+	let dst_start = Utc.from_utc_datetime(
+		&NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Sun, 2)
+			.and_then(|day| day.and_hms_opt(10, 0, 0))
+			.unwrap(),
+	);
+	let dst_end = Utc.from_utc_datetime(
+		&NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Sun, 1)
+			.and_then(|day| day.and_hms_opt(9, 0, 0))
+			.unwrap(),
+	);
+	if now >= dst_start && now < dst_end {
+		Duration::hours(-7)
+	} else {
+		Duration::hours(-8)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{DateTime, Duration, NaiveDate, Utc};
+	use futures::executor::block_on;
+
+	use super::{pacific_offset, QuotaTracker};
+	use crate::{
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client, Endpoint, Error,
+	};
+
+	fn utc(instant: &str) -> DateTime<Utc> {
+		instant.parse().unwrap()
+	}
+
+	fn pacific_date(instant: &str) -> NaiveDate {
+		let now = utc(instant);
+		(now + pacific_offset(now)).date_naive()
+	}
+
+	fn date(date: &str) -> NaiveDate {
+		date.parse().unwrap()
+	}
+
+	#[test]
+	fn switches_to_daylight_saving_time_on_the_second_sunday_of_march() {
+		assert_eq!(
+			pacific_offset(utc("2024-03-10T09:59:59Z")),
+			Duration::hours(-8)
+		);
+		assert_eq!(
+			pacific_offset(utc("2024-03-10T10:00:00Z")),
+			Duration::hours(-7)
+		);
+		assert_eq!(
+			pacific_offset(utc("2025-03-09T09:59:59Z")),
+			Duration::hours(-8)
+		);
+		assert_eq!(
+			pacific_offset(utc("2025-03-09T10:00:00Z")),
+			Duration::hours(-7)
+		);
+	}
+
+	#[test]
+	fn switches_to_standard_time_on_the_first_sunday_of_november() {
+		assert_eq!(
+			pacific_offset(utc("2024-11-03T08:59:59Z")),
+			Duration::hours(-7)
+		);
+		assert_eq!(
+			pacific_offset(utc("2024-11-03T09:00:00Z")),
+			Duration::hours(-8)
+		);
+		assert_eq!(
+			pacific_offset(utc("2025-11-02T08:59:59Z")),
+			Duration::hours(-7)
+		);
+		assert_eq!(
+			pacific_offset(utc("2025-11-02T09:00:00Z")),
+			Duration::hours(-8)
+		);
+	}
+
+	#[test]
+	fn starts_the_day_at_pacific_midnight() {
+		assert_eq!(pacific_date("2024-03-09T07:59:59Z"), date("2024-03-08"));
+		assert_eq!(pacific_date("2024-03-09T08:00:00Z"), date("2024-03-09"));
+		assert_eq!(pacific_date("2024-03-11T06:59:59Z"), date("2024-03-10"));
+		assert_eq!(pacific_date("2024-03-11T07:00:00Z"), date("2024-03-11"));
+		assert_eq!(pacific_date("2024-11-03T06:59:59Z"), date("2024-11-02"));
+		assert_eq!(pacific_date("2024-11-03T07:00:00Z"), date("2024-11-03"));
+		assert_eq!(pacific_date("2024-11-04T07:59:59Z"), date("2024-11-03"));
+		assert_eq!(pacific_date("2024-11-04T08:00:00Z"), date("2024-11-04"));
+	}
+
+	#[test]
+	fn refuses_requests_exceeding_an_enforced_budget() {
+		let tracker = QuotaTracker::new().daily_limit(150).enforce(true);
+
+		assert!(tracker.spend(Endpoint::SearchList).is_ok());
+		let exceeded = tracker.spend(Endpoint::SearchList).unwrap_err();
+		assert_eq!((exceeded.cost, exceeded.remaining), (100, 50));
+		assert!(tracker.spend(Endpoint::PlaylistsInsert).is_ok());
+		assert!(tracker.spend(Endpoint::ChannelsList).is_err());
+		assert_eq!(tracker.used(), 150);
+		assert_eq!(tracker.remaining(), 0);
+
+		tracker.reset();
+		assert!(tracker.spend(Endpoint::ChannelsList).is_ok());
+	}
+
+	#[test]
+	fn counts_without_refusing_unless_enforced() {
+		let tracker = QuotaTracker::new().daily_limit(150);
+
+		assert!(tracker.spend(Endpoint::SearchList).is_ok());
+		assert!(tracker.spend(Endpoint::SearchList).is_ok());
+		assert_eq!(tracker.used(), 200);
+		assert_eq!(tracker.remaining(), 0);
+	}
+
+	#[test]
+	fn client_sends_no_request_once_the_budget_is_exhausted() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+		transport.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+		let client = Client::builder(ApiKey::new("key"))
+			.transport(transport.clone())
+			.quota_tracker(QuotaTracker::new().daily_limit(1).enforce(true))
+			.build();
+
+		block_on(client.channels().id(["channel"])).unwrap();
+		let error = block_on(client.channels().id(["channel"])).unwrap_err();
+
+		match error {
+			Error::QuotaBudget {
+				endpoint: Endpoint::ChannelsList,
+				cost: 1,
+				remaining: 0,
+			} => {}
+			other => panic!("expected a quota budget error, got {:?}", other),
+		}
+		assert!(error.is_quota_exceeded());
+		assert_eq!(transport.requests().len(), 1);
+	}
+}
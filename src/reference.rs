@@ -0,0 +1,652 @@
+//! reference data like the video categories, languages and regions known to youtube
+//!
+//! the requests can be sent on their own, or through the [`Reference`](./struct.Reference.html)
+//! of a client which keeps the results for the life of the client.
+//!
+//! ```rust
+//! # use yt_api::{transport::{HttpResponse, MemoryTransport}, ApiKey, Client};
+//! # futures::executor::block_on(async {
+//! # let transport = MemoryTransport::new();
+//! # transport.push_response(HttpResponse::new(
+//! #     200,
+//! #     r#"{"items":[{"id":"10","snippet":{"title":"Music","assignable":true}}]}"#,
+//! # ));
+//! # let client = Client::builder(ApiKey::new("key")).transport(transport).build();
+//! let music = client.reference().find_category("US", "music").await?;
+//! assert_eq!(music.map(|category| category.id), Some(String::from("10")));
+//!
+//! // answered from the cache without another request
+//! let categories = client.reference().video_categories("US").await?;
+//! assert_eq!(categories.len(), 1);
+//! # Ok::<(), yt_api::Error>(())
+//! # }).unwrap();
+//! ```
+
+use std::{
+	collections::HashMap,
+	future::Future,
+	pin::Pin,
+	sync::{Arc, Mutex, PoisonError},
+	task::{Context, Poll},
+};
+
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	error::Validation,
+	fields::{Schema, LEAF},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the videoCategories endpoint
+///
+/// exactly one of [`id`](#method.id) and [`region_code`](#method.region_code) has to be set.
+pub struct VideoCategoriesList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<VideoCategoryListResponse, Error>>>,
+	data: Option<VideoCategoriesListData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VideoCategoriesListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	part: &'static str,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	region_code: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hl: Option<String>,
+}
+
+impl VideoCategoriesList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(VideoCategoriesListData {
+				fields: None,
+				part: "snippet",
+				id: Vec::new(),
+				region_code: None,
+				hl: client.hl().cloned(),
+			}),
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the categories to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the categories available in a region
+	#[must_use]
+	pub fn region_code(mut self, region_code: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.region_code = Some(region_code.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// language of the category titles
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.hl = Some(hl.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for VideoCategoriesList {
+	type Output = Result<VideoCategoryListResponse, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for VideoCategoriesListData {
+	const ENDPOINT: Endpoint = Endpoint::VideoCategoriesList;
+	type Response = VideoCategoryListResponse;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &VIDEO_CATEGORY_RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			self.id.is_empty() != self.region_code.is_none(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of id and regionCode must be set",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, _page_token: String) {}
+}
+
+/// request struct for the i18nLanguages endpoint
+pub struct I18nLanguagesList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<I18nLanguageListResponse, Error>>>,
+	data: Option<I18nListData>,
+}
+
+/// request struct for the i18nRegions endpoint
+pub struct I18nRegionsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<I18nRegionListResponse, Error>>>,
+	data: Option<I18nListData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct I18nListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	part: &'static str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hl: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+struct I18nLanguagesListData(I18nListData);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+struct I18nRegionsListData(I18nListData);
+
+impl I18nListData {
+	fn new(client: &Client) -> Self {
+		Self {
+			fields: None,
+			part: "snippet",
+			hl: client.hl().cloned(),
+		}
+	}
+}
+
+impl I18nLanguagesList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(I18nListData::new(&client)),
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// language of the language names
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.hl = Some(hl.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for I18nLanguagesList {
+	type Output = Result<I18nLanguageListResponse, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = I18nLanguagesListData(self.data.take().unwrap());
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for I18nLanguagesListData {
+	const ENDPOINT: Endpoint = Endpoint::I18nLanguagesList;
+	type Response = I18nLanguageListResponse;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.0.fields {
+			fields.validate(Self::ENDPOINT, &I18N_LANGUAGE_RESPONSE_SCHEMA)?;
+		}
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, _page_token: String) {}
+}
+
+impl I18nRegionsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(I18nListData::new(&client)),
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// language of the region names
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.hl = Some(hl.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+}
+
+impl Future for I18nRegionsList {
+	type Output = Result<I18nRegionListResponse, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = I18nRegionsListData(self.data.take().unwrap());
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for I18nRegionsListData {
+	const ENDPOINT: Endpoint = Endpoint::I18nRegionsList;
+	type Response = I18nRegionListResponse;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.0.fields {
+			fields.validate(Self::ENDPOINT, &I18N_REGION_RESPONSE_SCHEMA)?;
+		}
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, _page_token: String) {}
+}
+
+/// cached reference data of a client, created with
+/// [`Client::reference`](../struct.Client.html#method.reference)
+///
+/// the results are requested in the default language of the client and kept until the
+/// client and all of its clones are dropped.
+#[derive(Clone)]
+pub struct Reference {
+	client: Client,
+}
+
+/// results shared by all clones of a client
+#[derive(Debug, Default)]
+pub(crate) struct Cache {
+	video_categories: Mutex<HashMap<String, Arc<Vec<VideoCategory>>>>,
+	languages: Mutex<Option<Arc<Vec<I18nLanguage>>>>,
+	regions: Mutex<Option<Arc<Vec<I18nRegion>>>>,
+}
+
+impl Reference {
+	pub(crate) fn new(client: Client) -> Self {
+		Self { client }
+	}
+
+	/// video categories available in the region with the code `region_code`
+	pub async fn video_categories(
+		&self,
+		region_code: &str,
+	) -> Result<Arc<Vec<VideoCategory>>, Error> {
+		let cache = &self.client.reference_cache().video_categories;
+		if let Some(categories) = cache
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.get(region_code)
+		{
+			return Ok(Arc::clone(categories));
+		}
+		let response = VideoCategoriesList::with_client(self.client.clone())
+			.region_code(region_code)
+			.await?;
+		let categories = Arc::new(response.items);
+		cache
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.insert(String::from(region_code), Arc::clone(&categories));
+		Ok(categories)
+	}
+
+	/// video category of the region with the code `region_code` whose title is `name`,
+	/// ignoring case
+	pub async fn find_category(
+		&self,
+		region_code: &str,
+		name: &str,
+	) -> Result<Option<VideoCategory>, Error> {
+		let categories = self.video_categories(region_code).await?;
+		let name = name.to_lowercase();
+		Ok(categories
+			.iter()
+			.find(|category| {
+				category
+					.snippet
+					.as_ref()
+					.and_then(|snippet| snippet.title.as_ref())
+					.is_some_and(|title| title.to_lowercase() == name)
+			})
+			.cloned())
+	}
+
+	/// languages supported by youtube
+	pub async fn languages(&self) -> Result<Arc<Vec<I18nLanguage>>, Error> {
+		let cache = &self.client.reference_cache().languages;
+		if let Some(languages) = &*cache.lock().unwrap_or_else(PoisonError::into_inner) {
+			return Ok(Arc::clone(languages));
+		}
+		let response = I18nLanguagesList::with_client(self.client.clone()).await?;
+		let languages = Arc::new(response.items);
+		*cache.lock().unwrap_or_else(PoisonError::into_inner) = Some(Arc::clone(&languages));
+		Ok(languages)
+	}
+
+	/// regions supported by youtube
+	pub async fn regions(&self) -> Result<Arc<Vec<I18nRegion>>, Error> {
+		let cache = &self.client.reference_cache().regions;
+		if let Some(regions) = &*cache.lock().unwrap_or_else(PoisonError::into_inner) {
+			return Ok(Arc::clone(regions));
+		}
+		let response = I18nRegionsList::with_client(self.client.clone()).await?;
+		let regions = Arc::new(response.items);
+		*cache.lock().unwrap_or_else(PoisonError::into_inner) = Some(Arc::clone(&regions));
+		Ok(regions)
+	}
+}
+
+static VIDEO_CATEGORY_RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	(
+		"items",
+		&Schema(&[
+			("kind", &LEAF),
+			("etag", &LEAF),
+			("id", &LEAF),
+			(
+				"snippet",
+				&Schema(&[
+					("channelId", &LEAF),
+					("title", &LEAF),
+					("assignable", &LEAF),
+				]),
+			),
+		]),
+	),
+]);
+
+static I18N_LANGUAGE_RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	(
+		"items",
+		&Schema(&[
+			("kind", &LEAF),
+			("etag", &LEAF),
+			("id", &LEAF),
+			("snippet", &Schema(&[("hl", &LEAF), ("name", &LEAF)])),
+		]),
+	),
+]);
+
+static I18N_REGION_RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	(
+		"items",
+		&Schema(&[
+			("kind", &LEAF),
+			("etag", &LEAF),
+			("id", &LEAF),
+			("snippet", &Schema(&[("gl", &LEAF), ("name", &LEAF)])),
+		]),
+	),
+]);
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct VideoCategoryListResponse {
+	pub kind: String,
+	pub etag: String,
+	pub items: Vec<VideoCategory>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct VideoCategory {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<VideoCategorySnippet>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct VideoCategorySnippet {
+	pub channel_id: Option<String>,
+	pub title: Option<String>,
+	/// whether videos can be put into the category
+	pub assignable: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct I18nLanguageListResponse {
+	pub kind: String,
+	pub etag: String,
+	pub items: Vec<I18nLanguage>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct I18nLanguage {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<I18nLanguageSnippet>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct I18nLanguageSnippet {
+	/// language code which can be passed as `hl` or `relevanceLanguage`
+	pub hl: Option<String>,
+	pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct I18nRegionListResponse {
+	pub kind: String,
+	pub etag: String,
+	pub items: Vec<I18nRegion>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct I18nRegion {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<I18nRegionSnippet>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct I18nRegionSnippet {
+	/// region code which can be passed as `regionCode`
+	pub gl: Option<String>,
+	pub name: Option<String>,
+}
+
+impl Page for VideoCategoryListResponse {
+	type Item = VideoCategory;
+
+	fn next_page_token(&self) -> Option<&str> {
+		None
+	}
+
+	fn into_items(self) -> Vec<VideoCategory> {
+		self.items
+	}
+}
+
+impl Page for I18nLanguageListResponse {
+	type Item = I18nLanguage;
+
+	fn next_page_token(&self) -> Option<&str> {
+		None
+	}
+
+	fn into_items(self) -> Vec<I18nLanguage> {
+		self.items
+	}
+}
+
+impl Page for I18nRegionListResponse {
+	type Item = I18nRegion;
+
+	fn next_page_token(&self) -> Option<&str> {
+		None
+	}
+
+	fn into_items(self) -> Vec<I18nRegion> {
+		self.items
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{panic, thread};
+
+	use crate::{
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client,
+	};
+
+	#[test]
+	fn video_categories_survive_a_poisoned_cache() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(
+			200,
+			r#"{"items":[{"id":"10","snippet":{"title":"Music","assignable":true}}]}"#,
+		));
+		let client = Client::builder(ApiKey::new("key"))
+			.transport(transport.clone())
+			.build();
+
+		let poisoner = client.clone();
+		let result = thread::spawn(move || {
+			let _guard = poisoner.reference_cache().video_categories.lock().unwrap();
+			panic::panic_any("poison the cache");
+		})
+		.join();
+		assert!(result.is_err());
+		assert!(client.reference_cache().video_categories.is_poisoned());
+
+		let reference = client.reference();
+		let categories = futures::executor::block_on(reference.video_categories("US")).unwrap();
+		assert_eq!(categories[0].id, "10");
+		let cached = futures::executor::block_on(reference.video_categories("US")).unwrap();
+		assert_eq!(cached.len(), 1);
+		assert_eq!(transport.requests().len(), 1);
+	}
+}
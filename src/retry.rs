@@ -0,0 +1,295 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::Error;
+
+/// policy deciding whether and when a failed request is sent again
+///
+/// requests are retried on connection errors, `5xx` and `429` responses and on the
+/// `rateLimitExceeded`, `userRateLimitExceeded`, `backendError` and `internalError` reasons.
+/// The delay between attempts grows exponentially from `base_delay` up to `max_delay`.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+	max_attempts: u32,
+	base_delay: Duration,
+	max_delay: Duration,
+	jitter: f64,
+	max_elapsed_time: Option<Duration>,
+	retry_writes: bool,
+}
+
+impl RetryPolicy {
+	/// create a policy with up to 3 attempts starting with a delay of half a second
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			max_attempts: 3,
+			base_delay: Duration::from_millis(500),
+			max_delay: Duration::from_secs(32),
+			jitter: 0.5,
+			max_elapsed_time: Some(Duration::from_secs(60)),
+			retry_writes: false,
+		}
+	}
+
+	/// create a policy which never retries
+	#[must_use]
+	pub fn never() -> Self {
+		Self::new().max_attempts(1)
+	}
+
+	/// number of attempts including the first one
+	#[must_use]
+	pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+		self.max_attempts = max_attempts.max(1);
+		self
+	}
+
+	/// delay before the second attempt, doubled for every further attempt
+	#[must_use]
+	pub fn base_delay(mut self, base_delay: Duration) -> Self {
+		self.base_delay = base_delay;
+		self
+	}
+
+	/// upper bound of the delay between two attempts
+	#[must_use]
+	pub fn max_delay(mut self, max_delay: Duration) -> Self {
+		self.max_delay = max_delay;
+		self
+	}
+
+	/// fraction between `0.0` and `1.0` by which every delay is randomly shortened
+	#[must_use]
+	pub fn jitter(mut self, jitter: f64) -> Self {
+		self.jitter = jitter.clamp(0.0, 1.0);
+		self
+	}
+
+	/// stop retrying once this much time has passed since the first attempt
+	#[must_use]
+	pub fn max_elapsed_time(mut self, max_elapsed_time: impl Into<Option<Duration>>) -> Self {
+		self.max_elapsed_time = max_elapsed_time.into();
+		self
+	}
+
+	/// allow retrying requests which are not idempotent like inserts, defaults to `false`
+	#[must_use]
+	pub fn retry_writes(mut self, retry_writes: bool) -> Self {
+		self.retry_writes = retry_writes;
+		self
+	}
+
+	/// delay before the attempt following the failed `attempt`, or `None` to give up
+	pub(crate) fn retry_after(
+		&self,
+		error: &Error,
+		attempt: u32,
+		elapsed: Duration,
+		idempotent: bool,
+	) -> Option<Duration> {
+		if attempt >= self.max_attempts
+			|| !error.is_retryable()
+			|| !(idempotent || self.retry_writes)
+		{
+			return None;
+		}
+
+		let exponential = self
+			.base_delay
+			.checked_mul(2u32.saturating_pow(attempt - 1))
+			.unwrap_or(self.max_delay)
+			.min(self.max_delay);
+		let delay = exponential.mul_f64(1.0 - self.jitter * rand::thread_rng().gen::<f64>());
+		match self.max_elapsed_time {
+			Some(max_elapsed_time) if elapsed + delay > max_elapsed_time => None,
+			_ => Some(delay),
+		}
+	}
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use futures::executor::block_on;
+
+	use super::RetryPolicy;
+	use crate::{
+		transport::{HttpResponse, MemoryTransport},
+		ApiKey, Client, Endpoint, Error,
+	};
+
+	const RATE_LIMITED: &str = r#"{"error":{"code":429,"message":"Resource has been exhausted (e.g. check quota).","errors":[{"message":"Resource has been exhausted (e.g. check quota).","domain":"global","reason":"rateLimitExceeded"}],"status":"RESOURCE_EXHAUSTED"}}"#;
+	const USER_RATE_LIMITED: &str = r#"{"error":{"code":403,"message":"User Rate Limit Exceeded","errors":[{"message":"User Rate Limit Exceeded","domain":"usageLimits","reason":"userRateLimitExceeded"}]}}"#;
+
+	fn status(status: u16) -> Error {
+		Error::Http {
+			endpoint: Endpoint::ChannelsList,
+			url: String::from("https://www.googleapis.com/youtube/v3/channels"),
+			status,
+			body: String::new(),
+		}
+	}
+
+	fn client(transport: &MemoryTransport, retry_policy: RetryPolicy) -> Client {
+		Client::builder(ApiKey::new("key"))
+			.transport(transport.clone())
+			.retry_policy(retry_policy)
+			.build()
+	}
+
+	fn fast() -> RetryPolicy {
+		RetryPolicy::new().base_delay(Duration::from_millis(1))
+	}
+
+	#[test]
+	fn doubles_the_delay_up_to_the_maximum() {
+		let policy = RetryPolicy::new()
+			.max_attempts(10)
+			.jitter(0.0)
+			.max_delay(Duration::from_secs(3))
+			.max_elapsed_time(None);
+		let delays: Vec<_> = (1..=5)
+			.map(|attempt| policy.retry_after(&status(503), attempt, Duration::ZERO, true))
+			.collect();
+
+		assert_eq!(
+			delays,
+			[
+				Some(Duration::from_millis(500)),
+				Some(Duration::from_secs(1)),
+				Some(Duration::from_secs(2)),
+				Some(Duration::from_secs(3)),
+				Some(Duration::from_secs(3)),
+			]
+		);
+	}
+
+	#[test]
+	fn shortens_delays_by_at_most_the_jitter() {
+		let policy = RetryPolicy::new()
+			.max_attempts(10)
+			.jitter(0.25)
+			.max_elapsed_time(None);
+
+		for _ in 0..1000 {
+			let delay = policy
+				.retry_after(&status(503), 3, Duration::ZERO, true)
+				.unwrap();
+			assert!(delay >= Duration::from_millis(1500), "{:?}", delay);
+			assert!(delay <= Duration::from_secs(2), "{:?}", delay);
+		}
+	}
+
+	#[test]
+	fn stops_after_max_attempts() {
+		let policy = RetryPolicy::new().max_attempts(3);
+
+		assert!(policy
+			.retry_after(&status(503), 2, Duration::ZERO, true)
+			.is_some());
+		assert!(policy
+			.retry_after(&status(503), 3, Duration::ZERO, true)
+			.is_none());
+		assert!(RetryPolicy::never()
+			.retry_after(&status(503), 1, Duration::ZERO, true)
+			.is_none());
+	}
+
+	#[test]
+	fn stops_when_the_delay_would_exceed_max_elapsed_time() {
+		let policy = RetryPolicy::new()
+			.jitter(0.0)
+			.max_elapsed_time(Duration::from_secs(10));
+
+		assert!(policy
+			.retry_after(&status(503), 1, Duration::from_millis(9500), true)
+			.is_some());
+		assert!(policy
+			.retry_after(&status(503), 1, Duration::from_millis(9501), true)
+			.is_none());
+	}
+
+	#[test]
+	fn retries_writes_only_when_allowed() {
+		let policy = RetryPolicy::new();
+
+		assert!(policy
+			.retry_after(&status(503), 1, Duration::ZERO, false)
+			.is_none());
+		assert!(policy
+			.retry_writes(true)
+			.retry_after(&status(503), 1, Duration::ZERO, false)
+			.is_some());
+	}
+
+	#[test]
+	fn does_not_retry_permanent_errors() {
+		for code in &[400, 401, 403, 404] {
+			assert!(RetryPolicy::new()
+				.retry_after(&status(*code), 1, Duration::ZERO, true)
+				.is_none());
+		}
+	}
+
+	#[test]
+	fn retries_a_read_after_503() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(503, "Service Unavailable"));
+		transport.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+
+		block_on(client(&transport, fast()).channels().id(["channel"])).unwrap();
+
+		assert_eq!(transport.requests().len(), 2);
+	}
+
+	#[test]
+	fn sends_a_write_only_once() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(503, "Service Unavailable"));
+		transport.push_response(HttpResponse::new(200, r#"{"id":"playlist"}"#));
+
+		let error = block_on(client(&transport, fast()).insert_playlist("title")).unwrap_err();
+
+		assert!(error.is_retryable(), "{}", error);
+		assert_eq!(transport.requests().len(), 1);
+	}
+
+	#[test]
+	fn retries_rate_limited_requests() {
+		let transport = MemoryTransport::new();
+		transport.push_response(HttpResponse::new(429, RATE_LIMITED));
+		transport.push_response(HttpResponse::new(403, USER_RATE_LIMITED));
+		transport.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+
+		block_on(client(&transport, fast()).channels().id(["channel"])).unwrap();
+
+		assert_eq!(transport.requests().len(), 3);
+	}
+
+	#[test]
+	fn gives_up_after_max_attempts() {
+		let transport = MemoryTransport::new();
+		for _ in 0..3 {
+			transport.push_response(HttpResponse::new(503, "Service Unavailable"));
+		}
+		transport.push_response(HttpResponse::new(200, r#"{"items":[]}"#));
+
+		let error = block_on(client(&transport, fast()).channels().id(["channel"])).unwrap_err();
+
+		assert!(
+			matches!(error, Error::Http { status: 503, .. }),
+			"{}",
+			error
+		);
+		assert_eq!(transport.requests().len(), 3);
+	}
+}
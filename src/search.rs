@@ -5,40 +5,35 @@ use std::{
 };
 
 use chrono::{DateTime, Utc};
-use futures::future::BoxFuture;
-use log::debug;
+use futures::{future::BoxFuture, stream::BoxStream};
 use serde::{Deserialize, Serialize, Serializer};
-use snafu::{ResultExt, Snafu};
-
-use super::ApiKey;
-
-/// custom error type for the search endpoint
-#[derive(Debug, Snafu)]
-pub enum Error {
-	#[snafu(display("failed to connect to the api: {}", source))]
-	Connection { source: surf::Exception },
-	#[snafu(display("failed to deserialize: {} {}", string, source))]
-	Deserialization {
-		string: String,
-		source: serde_json::Error,
-	},
-	#[snafu(display("failed to serialize: {}", source))]
-	Serialization {
-		source: serde_urlencoded::ser::Error,
-	},
-}
+use snafu::ensure;
+
+pub use crate::common::{PageInfo, Thumbnail, Thumbnails};
+
+use crate::{
+	error::Validation,
+	fields::{Schema, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
 
 /// request struct for the search endpoint
 pub struct SearchList {
+	client: Client,
 	future: Option<BoxFuture<'static, Result<Response, Error>>>,
 	data: Option<SearchListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct SearchListData {
-	key: ApiKey,
-	part: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<SearchPart>,
 	#[serde(skip_serializing_if = "std::ops::Not::not")]
 	for_content_owner: bool,
 	#[serde(skip_serializing_if = "std::ops::Not::not")]
@@ -100,16 +95,22 @@ struct SearchListData {
 }
 
 impl SearchList {
-	const URL: &'static str = "https://www.googleapis.com/youtube/v3/search";
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
 
-	/// create struct with an [`ApiKey`](../struct.ApiKey.html)
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
 	#[must_use]
-	pub fn new(key: ApiKey) -> Self {
+	pub(crate) fn with_client(client: Client) -> Self {
 		Self {
 			future: None,
 			data: Some(SearchListData {
-				key,
-				part: String::from("snippet"),
+				fields: None,
+				part: vec![SearchPart::Snippet],
 				for_content_owner: false,
 				for_developer: false,
 				for_mine: false,
@@ -126,7 +127,7 @@ impl SearchList {
 				published_after: None,
 				published_before: None,
 				q: None,
-				region_code: None,
+				region_code: client.region_code().cloned(),
 				relevance_language: None,
 				safe_search: None,
 				topic_id: None,
@@ -140,9 +141,33 @@ impl SearchList {
 				video_syndicated: false,
 				video_type: None,
 			}),
+			page_limit: None,
+			item_limit: None,
+			client,
 		}
 	}
 
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = SearchPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
 	#[must_use]
 	pub fn for_content_owner(mut self) -> Self {
 		let mut data = self.data.take().unwrap();
@@ -377,6 +402,42 @@ impl SearchList {
 		self.data = Some(data);
 		self
 	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<SearchResult, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
 }
 
 impl Future for SearchList {
@@ -384,24 +445,89 @@ impl Future for SearchList {
 
 	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
 		if self.future.is_none() {
+			let client = self.client.clone();
 			let data = self.data.take().unwrap();
-			self.future = Some(Box::pin(async move {
-				let url = format!(
-					"{}?{}",
-					Self::URL,
-					serde_urlencoded::to_string(&data).context(Serialization)?
-				);
-				debug!("getting {}", url);
-				let response = surf::get(&url).recv_string().await.context(Connection)?;
-				serde_json::from_str(&response)
-					.with_context(move || Deserialization { string: response })
-			}));
+			self.future = Some(Box::pin(pagination::list(client, data)));
 		}
 
 		self.future.as_mut().unwrap().as_mut().poll(cx)
 	}
 }
 
+impl ListRequest for SearchListData {
+	const ENDPOINT: Endpoint = Endpoint::SearchList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("regionCode", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &SEARCH_RESULT_SCHEMA),
+]);
+
+static SEARCH_RESULT_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	(
+		"id",
+		&Schema(&[
+			("kind", &LEAF),
+			("videoId", &LEAF),
+			("channelId", &LEAF),
+			("playlistId", &LEAF),
+		]),
+	),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelId", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			("thumbnails", &THUMBNAILS),
+			("channelTitle", &LEAF),
+			("liveBroadcastContent", &LEAF),
+		]),
+	),
+]);
+
+/// resource part which can be requested with [`part`](./struct.SearchList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchPart {
+	Id,
+	Snippet,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ChannelType {
@@ -512,34 +638,30 @@ pub enum VideoType {
 	Movie,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Response {
 	pub kind: String,
 	pub etag: String,
+	pub next_page_token: Option<String>,
 	pub prev_page_token: Option<String>,
 	pub region_code: String,
 	pub page_info: PageInfo,
 	pub items: Vec<SearchResult>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PageInfo {
-	pub total_results: i64,
-	pub results_per_page: i64,
-}
-
-#[derive(Debug, Clone, Deserialize)]
+/// search result, the snippet is only set when it was requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
 pub struct SearchResult {
 	pub kind: String,
 	pub etag: String,
 	pub id: Id,
-	pub snippet: Snippet,
+	pub snippet: Option<Snippet>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Id {
 	pub kind: String,
 	pub video_id: Option<String>,
@@ -547,8 +669,8 @@ pub struct Id {
 	pub playlist_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Snippet {
 	pub published_at: Option<DateTime<Utc>>,
 	pub channel_id: Option<String>,
@@ -559,18 +681,14 @@ pub struct Snippet {
 	pub live_broadcast_content: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct Thumbnails {
-	default: Option<Thumbnail>,
-	medium: Option<Thumbnail>,
-	high: Option<Thumbnail>,
-	standard: Option<Thumbnail>,
-	maxres: Option<Thumbnail>,
-}
+impl Page for Response {
+	type Item = SearchResult;
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct Thumbnail {
-	pub url: String,
-	pub width: Option<u64>,
-	pub height: Option<u64>,
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<SearchResult> {
+		self.items
+	}
 }
@@ -0,0 +1,466 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::{PageInfo, Thumbnails},
+	error::Validation,
+	fields::{Schema, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the subscriptions endpoint
+///
+/// exactly one of [`channel_id`](#method.channel_id), [`id`](#method.id),
+/// [`mine`](#method.mine), [`my_recent_subscribers`](#method.my_recent_subscribers) and
+/// [`my_subscribers`](#method.my_subscribers) has to be set.
+pub struct SubscriptionsList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<SubscriptionsListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionsListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<SubscriptionPart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	channel_id: Option<String>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	mine: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	my_recent_subscribers: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	my_subscribers: Option<bool>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	for_channel_id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner_channel: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	order: Option<Order>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+}
+
+impl SubscriptionsList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(SubscriptionsListData {
+				fields: None,
+				part: vec![SubscriptionPart::Snippet],
+				channel_id: None,
+				id: Vec::new(),
+				mine: None,
+				my_recent_subscribers: None,
+				my_subscribers: None,
+				for_channel_id: Vec::new(),
+				max_results: None,
+				on_behalf_of_content_owner: None,
+				on_behalf_of_content_owner_channel: None,
+				order: None,
+				page_token: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = SubscriptionPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the subscriptions of a channel
+	#[must_use]
+	pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.channel_id = Some(channel_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the subscriptions to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// get the subscriptions of the authorized user
+	#[must_use]
+	pub fn mine(mut self, mine: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.mine = Some(mine);
+		self.data = Some(data);
+		self
+	}
+
+	/// get the subscribers of the authorized user, newest first
+	#[must_use]
+	pub fn my_recent_subscribers(mut self, my_recent_subscribers: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.my_recent_subscribers = Some(my_recent_subscribers);
+		self.data = Some(data);
+		self
+	}
+
+	/// get the subscribers of the authorized user in no particular order
+	#[must_use]
+	pub fn my_subscribers(mut self, my_subscribers: bool) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.my_subscribers = Some(my_subscribers);
+		self.data = Some(data);
+		self
+	}
+
+	/// only get subscriptions to these channels
+	#[must_use]
+	pub fn for_channel_id(
+		mut self,
+		for_channel_id: impl IntoIterator<Item = impl Into<String>>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.for_channel_id = for_channel_id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner_channel(
+		mut self,
+		on_behalf_of_content_owner_channel: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner_channel = Some(on_behalf_of_content_owner_channel.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn order(mut self, order: impl Into<Order>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.order = Some(order.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<Subscription, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for SubscriptionsList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for SubscriptionsListData {
+	const ENDPOINT: Endpoint = Endpoint::SubscriptionsList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		let filters = [
+			self.channel_id.is_some(),
+			!self.id.is_empty(),
+			self.mine.is_some(),
+			self.my_recent_subscribers.is_some(),
+			self.my_subscribers.is_some(),
+		];
+		ensure!(
+			filters.iter().filter(|set| **set).count() == 1,
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of channelId, id, mine, myRecentSubscribers and \
+				          mySubscribers must be set",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &SUBSCRIPTION_SCHEMA),
+]);
+
+static SUBSCRIPTION_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelTitle", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			(
+				"resourceId",
+				&Schema(&[("kind", &LEAF), ("channelId", &LEAF)]),
+			),
+			("channelId", &LEAF),
+			("thumbnails", &THUMBNAILS),
+		]),
+	),
+	(
+		"contentDetails",
+		&Schema(&[
+			("totalItemCount", &LEAF),
+			("newItemCount", &LEAF),
+			("activityType", &LEAF),
+		]),
+	),
+	(
+		"subscriberSnippet",
+		&Schema(&[
+			("title", &LEAF),
+			("description", &LEAF),
+			("channelId", &LEAF),
+			("thumbnails", &THUMBNAILS),
+		]),
+	),
+]);
+
+/// resource part which can be requested with
+/// [`part`](./struct.SubscriptionsList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SubscriptionPart {
+	ContentDetails,
+	Id,
+	Snippet,
+	SubscriberSnippet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Order {
+	Alphabetical,
+	Relevance,
+	Unread,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub prev_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<Subscription>,
+}
+
+/// subscription resource, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Subscription {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub content_details: Option<ContentDetails>,
+	pub subscriber_snippet: Option<SubscriberSnippet>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub published_at: Option<DateTime<Utc>>,
+	pub channel_title: Option<String>,
+	/// title of the channel subscribed to
+	pub title: Option<String>,
+	pub description: Option<String>,
+	/// channel subscribed to
+	pub resource_id: Resource,
+	/// channel of the subscriber
+	pub channel_id: Option<String>,
+	pub thumbnails: Option<Thumbnails>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Resource {
+	pub kind: String,
+	pub channel_id: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentDetails {
+	pub total_item_count: Option<u32>,
+	/// items published since the subscriber last read the channel activity
+	pub new_item_count: Option<u32>,
+	/// `all` or `uploads`
+	pub activity_type: Option<String>,
+}
+
+/// channel of the subscriber
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SubscriberSnippet {
+	pub title: Option<String>,
+	pub description: Option<String>,
+	pub channel_id: Option<String>,
+	pub thumbnails: Option<Thumbnails>,
+}
+
+impl Page for Response {
+	type Item = Subscription;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<Subscription> {
+		self.items
+	}
+}
@@ -0,0 +1,75 @@
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http_body_util::{BodyExt, Full};
+use hyper_rustls::HttpsConnector;
+use hyper_util::{
+	client::legacy::{connect::HttpConnector, Client},
+	rt::TokioExecutor,
+};
+
+use super::{HttpRequest, HttpResponse, HttpTransport, Method, TransportError};
+
+/// transport using a pooled [hyper](https://docs.rs/hyper) client with rustls
+///
+/// requests have to be polled from within a tokio runtime.
+#[derive(Debug, Clone)]
+pub struct HyperTransport {
+	client: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
+}
+
+impl HyperTransport {
+	#[must_use]
+	pub fn new() -> Self {
+		let connector = hyper_rustls::HttpsConnectorBuilder::new()
+			.with_webpki_roots()
+			.https_or_http()
+			.enable_http1()
+			.build();
+		Self {
+			client: Client::builder(TokioExecutor::new()).build(connector),
+		}
+	}
+}
+
+impl Default for HyperTransport {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl HttpTransport for HyperTransport {
+	fn send(
+		&self,
+		request: HttpRequest,
+	) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+		let client = self.client.clone();
+		Box::pin(async move {
+			let method = match request.method {
+				Method::Get => hyper::Method::GET,
+				Method::Post => hyper::Method::POST,
+				Method::Put => hyper::Method::PUT,
+				Method::Delete => hyper::Method::DELETE,
+			};
+			let mut builder = hyper::Request::builder().method(method).uri(&request.url);
+			for (name, value) in request.headers {
+				builder = builder.header(name, value);
+			}
+			let body = Full::new(Bytes::from(request.body.unwrap_or_default()));
+			let response = client.request(builder.body(body)?).await?;
+			let (parts, body) = response.into_parts();
+			let headers = parts
+				.headers
+				.iter()
+				.filter_map(|(name, value)| {
+					Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+				})
+				.collect();
+			let body = body.collect().await?.to_bytes().to_vec();
+			Ok(HttpResponse {
+				status: parts.status.as_u16(),
+				headers,
+				body,
+			})
+		})
+	}
+}
@@ -0,0 +1,79 @@
+use std::{
+	collections::VecDeque,
+	sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use futures::future::{self, BoxFuture};
+
+use super::{HttpRequest, HttpResponse, HttpTransport, TransportError};
+
+/// transport answering requests with queued responses instead of using the network
+///
+/// clones share their queue and the list of received requests, so a clone can be handed to a
+/// [`Client`](../struct.Client.html) while the original is used to inspect it.
+///
+/// ```rust
+/// # use yt_api::{transport::{HttpResponse, MemoryTransport}, ApiKey, Client};
+/// #
+/// # futures::executor::block_on(async {
+/// let transport = MemoryTransport::new();
+/// transport.push_response(HttpResponse::new(
+///     200,
+///     r#"{"kind":"","etag":"","pageInfo":{"totalResults":0,"resultsPerPage":0},"items":[]}"#,
+/// ));
+///
+/// let client = Client::builder(ApiKey::new("key"))
+///     .transport(transport.clone())
+///     .build();
+/// let result = client.playlist_items().playlist_id("some-playlist").await;
+///
+/// assert!(result.is_ok());
+/// assert_eq!(transport.requests().len(), 1);
+/// # });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTransport {
+	state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+	responses: VecDeque<HttpResponse>,
+	requests: Vec<HttpRequest>,
+}
+
+impl MemoryTransport {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// queue a response for the next request
+	pub fn push_response(&self, response: HttpResponse) {
+		self.state().responses.push_back(response);
+	}
+
+	/// all requests received so far
+	#[must_use]
+	pub fn requests(&self) -> Vec<HttpRequest> {
+		self.state().requests.clone()
+	}
+
+	fn state(&self) -> MutexGuard<'_, State> {
+		self.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+impl HttpTransport for MemoryTransport {
+	fn send(
+		&self,
+		request: HttpRequest,
+	) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+		let mut state = self.state();
+		let response = state.responses.pop_front().ok_or_else(|| {
+			format!("no response queued for {} {}", request.method, request.url).into()
+		});
+		state.requests.push(request);
+		Box::pin(future::ready(response))
+	}
+}
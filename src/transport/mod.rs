@@ -0,0 +1,180 @@
+//! http transports the [`Client`](../struct.Client.html) sends its requests with
+//!
+//! a transport is selected with
+//! [`ClientBuilder::transport`](../struct.ClientBuilder.html#method.transport). Without one the
+//! client uses the first transport enabled by the cargo features `native-client`, `curl-client`
+//! or `wasm-client` (surf), `reqwest-transport` and `hyper-transport` (or `hyper-client`).
+
+use std::{error, fmt, sync::Arc};
+
+use futures::future::BoxFuture;
+
+#[cfg(feature = "hyper-transport")]
+mod hyper;
+mod memory;
+#[cfg(feature = "reqwest-transport")]
+mod reqwest;
+#[cfg(any(
+	feature = "native-client",
+	feature = "curl-client",
+	feature = "wasm-client"
+))]
+mod surf;
+
+#[cfg(feature = "hyper-transport")]
+pub use self::hyper::HyperTransport;
+pub use self::memory::MemoryTransport;
+#[cfg(feature = "reqwest-transport")]
+pub use self::reqwest::ReqwestTransport;
+#[cfg(any(
+	feature = "native-client",
+	feature = "curl-client",
+	feature = "wasm-client"
+))]
+pub use self::surf::SurfTransport;
+
+/// error of a transport failing to deliver a request or to receive its response
+pub type TransportError = Box<dyn error::Error + Send + Sync + 'static>;
+
+/// http method of a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+	Get,
+	Post,
+	Put,
+	Delete,
+}
+
+impl Method {
+	/// whether sending the request twice has the same effect as sending it once
+	#[must_use]
+	pub fn is_idempotent(self) -> bool {
+		match self {
+			Self::Get | Self::Put | Self::Delete => true,
+			Self::Post => false,
+		}
+	}
+
+	#[must_use]
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Get => "GET",
+			Self::Post => "POST",
+			Self::Put => "PUT",
+			Self::Delete => "DELETE",
+		}
+	}
+}
+
+impl fmt::Display for Method {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// request handed to a transport
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+	pub method: Method,
+	pub url: String,
+	/// header names in lowercase with their values
+	pub headers: Vec<(&'static str, String)>,
+	pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+	/// create a request without headers and body
+	#[must_use]
+	pub fn new(method: Method, url: impl Into<String>) -> Self {
+		Self {
+			method,
+			url: url.into(),
+			headers: Vec::new(),
+			body: None,
+		}
+	}
+
+	#[must_use]
+	pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+		self.headers.push((name, value.into()));
+		self
+	}
+
+	#[must_use]
+	pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+		self.body = Some(body.into());
+		self
+	}
+}
+
+/// response received by a transport
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+	pub status: u16,
+	/// header names in lowercase with their values
+	pub headers: Vec<(String, String)>,
+	pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+	/// create a response without headers
+	#[must_use]
+	pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+		Self {
+			status,
+			headers: Vec::new(),
+			body: body.into(),
+		}
+	}
+
+	#[must_use]
+	pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers
+			.push((name.into().to_lowercase(), value.into()));
+		self
+	}
+
+	/// value of the first header called `name`, compared case insensitively
+	#[must_use]
+	pub fn get_header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(key, _)| key.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value.as_str())
+	}
+}
+
+/// sends http requests for a [`Client`](../struct.Client.html)
+///
+/// implementations only deliver requests, status codes other than success are not errors.
+pub trait HttpTransport: Send + Sync {
+	fn send(
+		&self,
+		request: HttpRequest,
+	) -> BoxFuture<'static, Result<HttpResponse, TransportError>>;
+}
+
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+	fn send(
+		&self,
+		request: HttpRequest,
+	) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+		(**self).send(request)
+	}
+}
+
+/// transport used by clients which weren't given one, depending on the enabled features
+#[allow(unreachable_code)]
+pub(crate) fn default_transport() -> Option<Arc<dyn HttpTransport>> {
+	#[cfg(any(
+		feature = "native-client",
+		feature = "curl-client",
+		feature = "wasm-client"
+	))]
+	return Some(Arc::new(SurfTransport::new()));
+	#[cfg(feature = "reqwest-transport")]
+	return Some(Arc::new(ReqwestTransport::new()));
+	#[cfg(feature = "hyper-transport")]
+	return Some(Arc::new(HyperTransport::new()));
+	None
+}
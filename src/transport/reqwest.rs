@@ -0,0 +1,67 @@
+use futures::future::BoxFuture;
+
+use super::{HttpRequest, HttpResponse, HttpTransport, Method, TransportError};
+
+/// transport using a [reqwest](https://docs.rs/reqwest) client
+///
+/// requests have to be polled from within a tokio runtime.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+	client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// use an existing, possibly customized, reqwest client
+	#[must_use]
+	pub fn with_client(client: reqwest::Client) -> Self {
+		Self { client }
+	}
+}
+
+impl HttpTransport for ReqwestTransport {
+	fn send(
+		&self,
+		request: HttpRequest,
+	) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+		let method = match request.method {
+			Method::Get => reqwest::Method::GET,
+			Method::Post => reqwest::Method::POST,
+			Method::Put => reqwest::Method::PUT,
+			Method::Delete => reqwest::Method::DELETE,
+		};
+		let mut builder = self.client.request(method, &request.url);
+		for (name, value) in request.headers {
+			builder = builder.header(name, value);
+		}
+		if let Some(body) = request.body {
+			builder = builder.body(body);
+		}
+		Box::pin(async move {
+			// the error message of reqwest includes the url, which carries the api key
+			let response = builder.send().await.map_err(reqwest::Error::without_url)?;
+			let status = response.status().as_u16();
+			let headers = response
+				.headers()
+				.iter()
+				.filter_map(|(name, value)| {
+					Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+				})
+				.collect();
+			let body = response
+				.bytes()
+				.await
+				.map_err(reqwest::Error::without_url)?
+				.to_vec();
+			Ok(HttpResponse {
+				status,
+				headers,
+				body,
+			})
+		})
+	}
+}
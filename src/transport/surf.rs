@@ -0,0 +1,71 @@
+use futures::future::BoxFuture;
+
+use super::{HttpRequest, HttpResponse, HttpTransport, Method, TransportError};
+
+type SendFn =
+	dyn Fn(HttpRequest) -> BoxFuture<'static, Result<HttpResponse, TransportError>> + Send + Sync;
+
+/// transport using a pooled [surf](https://docs.rs/surf) client
+pub struct SurfTransport {
+	send: Box<SendFn>,
+}
+
+impl SurfTransport {
+	#[must_use]
+	pub fn new() -> Self {
+		let client = surf::Client::new();
+		let send = move |request: HttpRequest| -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+			let mut builder = match request.method {
+				Method::Get => client.get(&request.url),
+				Method::Post => client.post(&request.url),
+				Method::Put => client.put(&request.url),
+				Method::Delete => client.delete(&request.url),
+			};
+			for (name, value) in &request.headers {
+				builder = builder.set_header(name, value);
+			}
+			if let Some(body) = request.body {
+				builder = builder.body_bytes(body);
+			}
+			Box::pin(async move {
+				let mut response = builder.await?;
+				let headers = response
+					.headers()
+					.iter()
+					.map(|(name, value)| (name.to_lowercase(), value.to_string()))
+					.collect();
+				let body = response.body_bytes().await?;
+				Ok(HttpResponse {
+					status: response.status().as_u16(),
+					headers,
+					body,
+				})
+			})
+		};
+
+		Self {
+			send: Box::new(send),
+		}
+	}
+}
+
+impl Default for SurfTransport {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl std::fmt::Debug for SurfTransport {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SurfTransport").finish()
+	}
+}
+
+impl HttpTransport for SurfTransport {
+	fn send(
+		&self,
+		request: HttpRequest,
+	) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+		(self.send)(request)
+	}
+}
@@ -0,0 +1,716 @@
+use std::{
+	collections::HashMap,
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+	time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::BoxFuture, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+	common::{Localization, PageInfo, Thumbnails, LOCALIZATION},
+	de,
+	error::Validation,
+	fields::{Schema, ANY, LEAF, PAGE_INFO, THUMBNAILS},
+	pagination::{self, ListRequest, Page},
+	params, Client, Credentials, Endpoint, Error, FieldMask, RetryPolicy,
+};
+
+/// request struct for the videos endpoint
+///
+/// exactly one of [`id`](#method.id), [`chart`](#method.chart) and
+/// [`my_rating`](#method.my_rating) has to be set.
+pub struct VideosList {
+	client: Client,
+	future: Option<BoxFuture<'static, Result<Response, Error>>>,
+	data: Option<VideosListData>,
+	page_limit: Option<usize>,
+	item_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VideosListData {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<FieldMask>,
+	#[serde(serialize_with = "params::comma_separated")]
+	part: Vec<VideoPart>,
+	#[serde(
+		skip_serializing_if = "Vec::is_empty",
+		serialize_with = "params::comma_separated"
+	)]
+	id: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	chart: Option<Chart>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	my_rating: Option<MyRating>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hl: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_height: Option<u16>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_width: Option<u16>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	max_results: Option<u8>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	on_behalf_of_content_owner: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	page_token: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	region_code: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	video_category_id: Option<String>,
+}
+
+impl VideosList {
+	/// create struct with [`Credentials`](../auth/enum.Credentials.html), like an
+	/// [`ApiKey`](../struct.ApiKey.html)
+	#[must_use]
+	pub fn new(credentials: impl Into<Credentials>) -> Self {
+		Self::with_client(Client::new(credentials))
+	}
+
+	/// create struct using the defaults and connection pool of a
+	/// [`Client`](../struct.Client.html)
+	#[must_use]
+	pub(crate) fn with_client(client: Client) -> Self {
+		Self {
+			future: None,
+			data: Some(VideosListData {
+				fields: None,
+				part: vec![VideoPart::Snippet],
+				id: Vec::new(),
+				chart: None,
+				my_rating: None,
+				hl: client.hl().cloned(),
+				max_height: None,
+				max_width: None,
+				max_results: None,
+				on_behalf_of_content_owner: None,
+				page_token: None,
+				region_code: None,
+				video_category_id: None,
+			}),
+			page_limit: None,
+			item_limit: None,
+			client,
+		}
+	}
+
+	/// only include the fields selected by `fields` in the response
+	///
+	/// [`pages`](#method.pages) and [`into_stream`](#method.into_stream) stop after the first
+	/// page unless `nextPageToken` is selected.
+	#[must_use]
+	pub fn fields(mut self, fields: FieldMask) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.fields = Some(fields);
+		self.data = Some(data);
+		self
+	}
+
+	/// resource parts included in the response, defaults to the snippet
+	#[must_use]
+	pub fn part(mut self, part: impl IntoIterator<Item = VideoPart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.part = part.into_iter().collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// ids of the videos to get
+	#[must_use]
+	pub fn id(mut self, id: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.id = id.into_iter().map(Into::into).collect();
+		self.data = Some(data);
+		self
+	}
+
+	/// most popular videos, of the region of the client unless
+	/// [`region_code`](#method.region_code) is set
+	#[must_use]
+	pub fn chart(mut self, chart: impl Into<Chart>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.chart = Some(chart.into());
+		if data.region_code.is_none() {
+			data.region_code = self.client.region_code().cloned();
+		}
+		self.data = Some(data);
+		self
+	}
+
+	/// get the videos the authorized user rated
+	#[must_use]
+	pub fn my_rating(mut self, my_rating: impl Into<MyRating>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.my_rating = Some(my_rating.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn hl(mut self, hl: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.hl = Some(hl.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_height(mut self, max_height: impl Into<u16>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_height = Some(max_height.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_width(mut self, max_width: impl Into<u16>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_width = Some(max_width.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn max_results(mut self, max_results: impl Into<u8>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.max_results = Some(max_results.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn on_behalf_of_content_owner(
+		mut self,
+		on_behalf_of_content_owner: impl Into<String>,
+	) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.on_behalf_of_content_owner = Some(on_behalf_of_content_owner.into());
+		self.data = Some(data);
+		self
+	}
+
+	#[must_use]
+	pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.page_token = Some(page_token.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// region of the chart, only valid together with [`chart`](#method.chart)
+	#[must_use]
+	pub fn region_code(mut self, region_code: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.region_code = Some(region_code.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// category of the chart, only valid together with [`chart`](#method.chart)
+	#[must_use]
+	pub fn video_category_id(mut self, video_category_id: impl Into<String>) -> Self {
+		let mut data = self.data.take().unwrap();
+		data.video_category_id = Some(video_category_id.into());
+		self.data = Some(data);
+		self
+	}
+
+	/// override the [`RetryPolicy`](../struct.RetryPolicy.html) of the client for this request
+	#[must_use]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.client = self.client.with_retry_policy(retry_policy);
+		self
+	}
+
+	/// stop [`pages`](#method.pages) and [`into_stream`](#method.into_stream) after
+	/// `page_limit` pages
+	#[must_use]
+	pub fn page_limit(mut self, page_limit: usize) -> Self {
+		self.page_limit = Some(page_limit);
+		self
+	}
+
+	/// stop [`into_stream`](#method.into_stream) after `item_limit` items
+	#[must_use]
+	pub fn item_limit(mut self, item_limit: usize) -> Self {
+		self.item_limit = Some(item_limit);
+		self
+	}
+
+	/// stream of all response pages, following the `nextPageToken` until the last page
+	#[must_use]
+	pub fn pages(mut self) -> BoxStream<'static, Result<Response, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::pages(self.client.clone(), data, self.page_limit)
+	}
+
+	/// stream of the items of all response pages, fetching the pages lazily
+	#[must_use]
+	pub fn into_stream(mut self) -> BoxStream<'static, Result<Video, Error>> {
+		let data = self.data.take().unwrap();
+		pagination::items(self.client.clone(), data, self.page_limit, self.item_limit)
+	}
+}
+
+impl Future for VideosList {
+	type Output = Result<Response, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.future.is_none() {
+			let client = self.client.clone();
+			let data = self.data.take().unwrap();
+			self.future = Some(Box::pin(pagination::list(client, data)));
+		}
+
+		self.future.as_mut().unwrap().as_mut().poll(cx)
+	}
+}
+
+impl ListRequest for VideosListData {
+	const ENDPOINT: Endpoint = Endpoint::VideosList;
+	type Response = Response;
+
+	fn validate(&self) -> Result<(), Error> {
+		if let Some(fields) = &self.fields {
+			fields.validate(Self::ENDPOINT, &RESPONSE_SCHEMA)?;
+		}
+		ensure!(
+			!self.part.is_empty(),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "at least one part must be requested",
+			}
+		);
+		let filters = [
+			!self.id.is_empty(),
+			self.chart.is_some(),
+			self.my_rating.is_some(),
+		];
+		ensure!(
+			filters.iter().filter(|set| **set).count() == 1,
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "exactly one of id, chart and myRating must be set",
+			}
+		);
+		ensure!(
+			self.chart.is_some()
+				|| (self.region_code.is_none() && self.video_category_id.is_none()),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "regionCode and videoCategoryId can only be used with chart",
+			}
+		);
+		ensure!(
+			self.max_results.is_none_or(|max_results| max_results <= 50),
+			Validation {
+				endpoint: Self::ENDPOINT,
+				message: "maxResults must be between 0 and 50",
+			}
+		);
+		Ok(())
+	}
+
+	fn set_page_token(&mut self, page_token: String) {
+		self.page_token = Some(page_token);
+	}
+}
+
+static RESPONSE_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("nextPageToken", &LEAF),
+	("prevPageToken", &LEAF),
+	("pageInfo", &PAGE_INFO),
+	("items", &VIDEO_SCHEMA),
+]);
+
+static VIDEO_SCHEMA: Schema = Schema(&[
+	("kind", &LEAF),
+	("etag", &LEAF),
+	("id", &LEAF),
+	(
+		"snippet",
+		&Schema(&[
+			("publishedAt", &LEAF),
+			("channelId", &LEAF),
+			("title", &LEAF),
+			("description", &LEAF),
+			("thumbnails", &THUMBNAILS),
+			("channelTitle", &LEAF),
+			("tags", &LEAF),
+			("categoryId", &LEAF),
+			("liveBroadcastContent", &LEAF),
+			("defaultLanguage", &LEAF),
+			("localized", &LOCALIZATION),
+			("defaultAudioLanguage", &LEAF),
+		]),
+	),
+	(
+		"contentDetails",
+		&Schema(&[
+			("duration", &LEAF),
+			("dimension", &LEAF),
+			("definition", &LEAF),
+			("caption", &LEAF),
+			("licensedContent", &LEAF),
+			(
+				"regionRestriction",
+				&Schema(&[("allowed", &LEAF), ("blocked", &LEAF)]),
+			),
+			("contentRating", &ANY),
+			("projection", &LEAF),
+			("hasCustomThumbnail", &LEAF),
+		]),
+	),
+	(
+		"statistics",
+		&Schema(&[
+			("viewCount", &LEAF),
+			("likeCount", &LEAF),
+			("dislikeCount", &LEAF),
+			("favoriteCount", &LEAF),
+			("commentCount", &LEAF),
+		]),
+	),
+	(
+		"status",
+		&Schema(&[
+			("uploadStatus", &LEAF),
+			("failureReason", &LEAF),
+			("rejectionReason", &LEAF),
+			("privacyStatus", &LEAF),
+			("publishAt", &LEAF),
+			("license", &LEAF),
+			("embeddable", &LEAF),
+			("publicStatsViewable", &LEAF),
+			("madeForKids", &LEAF),
+			("selfDeclaredMadeForKids", &LEAF),
+		]),
+	),
+	(
+		"player",
+		&Schema(&[
+			("embedHtml", &LEAF),
+			("embedHeight", &LEAF),
+			("embedWidth", &LEAF),
+		]),
+	),
+	(
+		"topicDetails",
+		&Schema(&[
+			("topicIds", &LEAF),
+			("relevantTopicIds", &LEAF),
+			("topicCategories", &LEAF),
+		]),
+	),
+	(
+		"recordingDetails",
+		&Schema(&[
+			("recordingDate", &LEAF),
+			(
+				"location",
+				&Schema(&[
+					("latitude", &LEAF),
+					("longitude", &LEAF),
+					("altitude", &LEAF),
+				]),
+			),
+			("locationDescription", &LEAF),
+		]),
+	),
+	(
+		"liveStreamingDetails",
+		&Schema(&[
+			("actualStartTime", &LEAF),
+			("actualEndTime", &LEAF),
+			("scheduledStartTime", &LEAF),
+			("scheduledEndTime", &LEAF),
+			("concurrentViewers", &LEAF),
+			("activeLiveChatId", &LEAF),
+		]),
+	),
+	("localizations", &ANY),
+]);
+
+/// resource part which can be requested with [`part`](./struct.VideosList.html#method.part)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VideoPart {
+	ContentDetails,
+	Id,
+	LiveStreamingDetails,
+	Localizations,
+	Player,
+	RecordingDetails,
+	Snippet,
+	Statistics,
+	Status,
+	TopicDetails,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Chart {
+	MostPopular,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MyRating {
+	Dislike,
+	Like,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Response {
+	pub kind: String,
+	pub etag: String,
+	pub next_page_token: Option<String>,
+	pub prev_page_token: Option<String>,
+	pub page_info: PageInfo,
+	pub items: Vec<Video>,
+}
+
+/// video resource, its parts are only set when they were requested
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Video {
+	pub kind: String,
+	pub etag: String,
+	pub id: String,
+	pub snippet: Option<Snippet>,
+	pub content_details: Option<ContentDetails>,
+	pub statistics: Option<Statistics>,
+	pub status: Option<Status>,
+	pub player: Option<Player>,
+	pub topic_details: Option<TopicDetails>,
+	pub recording_details: Option<RecordingDetails>,
+	pub live_streaming_details: Option<LiveStreamingDetails>,
+	/// translations of the title and description keyed by language
+	pub localizations: Option<HashMap<String, Localization>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+	pub published_at: Option<DateTime<Utc>>,
+	pub channel_id: Option<String>,
+	pub title: Option<String>,
+	pub description: Option<String>,
+	pub thumbnails: Option<Thumbnails>,
+	pub channel_title: Option<String>,
+	pub tags: Vec<String>,
+	pub category_id: Option<String>,
+	pub live_broadcast_content: Option<String>,
+	pub default_language: Option<String>,
+	/// title and description in the language requested with `hl`
+	pub localized: Option<Localization>,
+	pub default_audio_language: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContentDetails {
+	/// length of the video as an ISO 8601 duration like `PT1H2M3S`
+	pub duration: String,
+	pub dimension: Option<String>,
+	pub definition: Option<String>,
+	/// `"true"` if captions are available
+	pub caption: Option<String>,
+	pub licensed_content: Option<bool>,
+	pub region_restriction: Option<RegionRestriction>,
+	/// ratings of the video by rating scheme, like `ytRating`
+	pub content_rating: HashMap<String, serde_json::Value>,
+	pub projection: Option<String>,
+	pub has_custom_thumbnail: Option<bool>,
+}
+
+impl ContentDetails {
+	/// length of the video parsed from the ISO 8601 `duration`
+	#[must_use]
+	pub fn parsed_duration(&self) -> Option<Duration> {
+		let rest = self.duration.strip_prefix('P')?;
+		let mut seconds = 0u64;
+		let mut number = String::new();
+		let mut time = false;
+		let mut components = 0;
+		for c in rest.chars() {
+			match c {
+				'0'..='9' => number.push(c),
+				'T' if !time && number.is_empty() => {
+					time = true;
+					components = 0;
+				}
+				unit => {
+					let value = number.parse::<u64>().ok()?;
+					number.clear();
+					let factor = match (unit, time) {
+						('W', false) => 604_800,
+						('D', false) => 86_400,
+						('H', true) => 3_600,
+						('M', true) => 60,
+						('S', true) => 1,
+						_ => return None,
+					};
+					seconds = seconds.checked_add(value.checked_mul(factor)?)?;
+					components += 1;
+				}
+			}
+		}
+		// `P`, `PT` and `P1DT` lack the component after the designator
+		if number.is_empty() && components > 0 {
+			Some(Duration::from_secs(seconds))
+		} else {
+			None
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RegionRestriction {
+	pub allowed: Vec<String>,
+	pub blocked: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Statistics {
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub view_count: Option<u64>,
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub like_count: Option<u64>,
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub dislike_count: Option<u64>,
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub favorite_count: Option<u64>,
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub comment_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Status {
+	pub upload_status: Option<String>,
+	pub failure_reason: Option<String>,
+	pub rejection_reason: Option<String>,
+	pub privacy_status: Option<String>,
+	pub publish_at: Option<DateTime<Utc>>,
+	pub license: Option<String>,
+	pub embeddable: Option<bool>,
+	pub public_stats_viewable: Option<bool>,
+	pub made_for_kids: Option<bool>,
+	pub self_declared_made_for_kids: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Player {
+	pub embed_html: Option<String>,
+	pub embed_height: Option<u64>,
+	pub embed_width: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TopicDetails {
+	pub topic_ids: Vec<String>,
+	pub relevant_topic_ids: Vec<String>,
+	/// wikipedia urls describing the content of the video
+	pub topic_categories: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RecordingDetails {
+	pub recording_date: Option<DateTime<Utc>>,
+	pub location: Option<GeoPoint>,
+	pub location_description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GeoPoint {
+	pub latitude: f64,
+	pub longitude: f64,
+	pub altitude: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveStreamingDetails {
+	pub actual_start_time: Option<DateTime<Utc>>,
+	pub actual_end_time: Option<DateTime<Utc>>,
+	pub scheduled_start_time: Option<DateTime<Utc>>,
+	pub scheduled_end_time: Option<DateTime<Utc>>,
+	#[serde(deserialize_with = "de::number_from_string")]
+	pub concurrent_viewers: Option<u64>,
+	pub active_live_chat_id: Option<String>,
+}
+
+impl Page for Response {
+	type Item = Video;
+
+	fn next_page_token(&self) -> Option<&str> {
+		self.next_page_token.as_deref()
+	}
+
+	fn into_items(self) -> Vec<Video> {
+		self.items
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::ContentDetails;
+
+	fn parse(duration: &str) -> Option<Duration> {
+		ContentDetails {
+			duration: String::from(duration),
+			..ContentDetails::default()
+		}
+		.parsed_duration()
+	}
+
+	#[test]
+	fn parses_durations() {
+		assert_eq!(parse("PT15S"), Some(Duration::from_secs(15)));
+		assert_eq!(parse("PT4M13S"), Some(Duration::from_secs(253)));
+		assert_eq!(parse("PT1H2M3S"), Some(Duration::from_secs(3723)));
+		assert_eq!(parse("P1DT2H"), Some(Duration::from_secs(93_600)));
+		assert_eq!(parse("P2W"), Some(Duration::from_secs(1_209_600)));
+		assert_eq!(parse("P0D"), Some(Duration::from_secs(0)));
+	}
+
+	#[test]
+	fn rejects_malformed_durations() {
+		for duration in &[
+			"",
+			"P",
+			"PT",
+			"P1DT",
+			"1H",
+			"PT1",
+			"PT1X",
+			"P1H",
+			"PT1D",
+			"PTT1S",
+			"P1DT1HT1S",
+			"PTS",
+			"PT-1S",
+		] {
+			assert_eq!(parse(duration), None, "{}", duration);
+		}
+	}
+}